@@ -0,0 +1,332 @@
+//! Shared runtime for Advent of Code solutions.
+//!
+//! A day implements [`Solution`] and calls [`main!`], which reads the puzzle
+//! input from stdin and prints its answers as JSON lines, the protocol the
+//! `aoc` runner parses. A part that fails or panics answers `--`. Parsing and
+//! each part are timed separately and reported as `timing` messages.
+//!
+//! Solutions can also send [`log`], [`warn`] and [`progress`] messages, which
+//! the runner shows apart from the answers.
+
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Why a step of a solution produced no answer.
+#[derive(Debug)]
+pub enum Error {
+    /// The part has not been written yet
+    NotImplemented,
+    /// The input did not have the expected shape
+    Parse(String),
+    /// The input was valid but has no answer, e.g. an unreachable goal
+    NoSolution(String),
+    Other(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotImplemented => write!(f, "not implemented"),
+            Self::Parse(reason) => write!(f, "parse error: {}", reason),
+            Self::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            Self::Other(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(e: std::num::ParseIntError) -> Self {
+        Self::Parse(e.to_string())
+    }
+}
+
+/// One day's puzzle: the input is parsed once and both parts work on the result.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, Error>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, Error>;
+}
+
+/// Runs `S` on stdin and prints its answers; what [`main!`] expands to.
+pub fn run<S: Solution>() {
+    let mut raw = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut raw) {
+        eprintln!("failed to read input: {}", e);
+    }
+    // Not locked for the whole run, so solution threads can still log
+    solve::<S>(&raw, &mut io::stdout());
+}
+
+/// Runs `S` on `raw` and writes its timings and answers to `out`.
+pub fn solve<S: Solution>(raw: &str, out: &mut impl Write) {
+    let input = match timed(out, "parse", || S::parse(raw.trim_end())) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("parse: {}", e);
+            write_line(out, &answer(1, "--"));
+            write_line(out, &answer(2, "--"));
+            return;
+        }
+    };
+    let part1 = timed(out, "part1", || S::part1(&input));
+    report(out, 1, part1);
+    let part2 = timed(out, "part2", || S::part2(&input));
+    report(out, 2, part2);
+}
+
+/// Runs a step under [`catch`] and reports how long it took, even if it failed.
+fn timed<T>(
+    out: &mut impl Write,
+    stage: &str,
+    step: impl FnOnce() -> Result<T, Error>,
+) -> Result<T, Error> {
+    let start = Instant::now();
+    let result = catch(step);
+    write_line(out, &timing(stage, start.elapsed()));
+    result
+}
+
+/// Runs a step, turning a panic into an error so the other part still runs.
+fn catch<T>(step: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    panic::catch_unwind(AssertUnwindSafe(step)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("panicked"));
+        Err(Error::Other(message))
+    })
+}
+
+fn report<T: Display>(out: &mut impl Write, part: u8, result: Result<T, Error>) {
+    match result {
+        Ok(value) => write_line(out, &answer(part, value)),
+        Err(e) => {
+            write_line(out, &answer(part, "--"));
+            if !matches!(e, Error::NotImplemented) {
+                eprintln!("part {}: {}", part, e);
+            }
+        }
+    }
+}
+
+/// Version of the output protocol, sent with every message.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// A debug message, shown with the input's results.
+pub fn log(message: impl Display) {
+    println!("{}", message_line("log", &message.to_string()));
+}
+
+/// Something suspicious about the input or the solution, highlighted in the results.
+pub fn warn(message: impl Display) {
+    println!("{}", message_line("warning", &message.to_string()));
+}
+
+/// How far a long-running part has come; shown while the solution runs.
+pub fn progress(done: u64, total: Option<u64>) {
+    println!("{}", progress_line(done, total));
+}
+
+fn message_line(kind: &str, message: &str) -> String {
+    line(kind, &format!(r#""message":{}"#, json_string(message)))
+}
+
+fn progress_line(done: u64, total: Option<u64>) -> String {
+    let total = total.map_or_else(|| String::from("null"), |total| total.to_string());
+    line("progress", &format!(r#""done":{},"total":{}"#, done, total))
+}
+
+fn timing(stage: &str, elapsed: Duration) -> String {
+    line(
+        "timing",
+        &format!(
+            r#""stage":{},"micros":{}"#,
+            json_string(stage),
+            elapsed.as_micros()
+        ),
+    )
+}
+
+fn answer(part: u8, value: impl Display) -> String {
+    line(
+        "answer",
+        &format!(
+            r#""part":{},"value":{}"#,
+            part,
+            json_string(&value.to_string())
+        ),
+    )
+}
+
+fn line(kind: &str, fields: &str) -> String {
+    format!(
+        r#"{{"aoc":{},"type":"{}",{}}}"#,
+        PROTOCOL_VERSION, kind, fields
+    )
+}
+
+/// A closed stdout leaves nobody to report to, so write errors are ignored.
+fn write_line(out: &mut impl Write, line: &str) {
+    let _ = writeln!(out, "{}", line);
+}
+
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Defines `fn main` for a [`Solution`]: `aoc_core::main!(Day);`
+#[macro_export]
+macro_rules! main {
+    ($solution:ty) => {
+        fn main() {
+            $crate::run::<$solution>();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Part 1 sums the numbers, part 2 panics on purpose.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<u64, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(_: &Self::Input) -> Result<u64, Error> {
+            panic!("boom")
+        }
+    }
+
+    fn solve_lines<S: Solution>(raw: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        solve::<S>(raw, &mut out);
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(json_string(r"C:\dir"), r#""C:\\dir""#);
+        assert_eq!(json_string("a\nb\r\tc"), r#""a\nb\r\tc""#);
+        assert_eq!(json_string("\u{0}\u{1b}\u{7f}"), r#""\u0000\u001b\u007f""#);
+        assert_eq!(json_string("é ★"), "\"é ★\"");
+    }
+
+    #[test]
+    fn formats_each_message() {
+        assert_eq!(
+            answer(1, 1129),
+            r#"{"aoc":1,"type":"answer","part":1,"value":"1129"}"#
+        );
+        assert_eq!(
+            timing("part2", Duration::from_micros(1520)),
+            r#"{"aoc":1,"type":"timing","stage":"part2","micros":1520}"#
+        );
+        assert_eq!(
+            message_line("log", "grid is 140x140"),
+            r#"{"aoc":1,"type":"log","message":"grid is 140x140"}"#
+        );
+        assert_eq!(
+            message_line("warning", "trailing \"blank\" line"),
+            r#"{"aoc":1,"type":"warning","message":"trailing \"blank\" line"}"#
+        );
+        assert_eq!(
+            progress_line(40, Some(100)),
+            r#"{"aoc":1,"type":"progress","done":40,"total":100}"#
+        );
+        assert_eq!(
+            progress_line(7, None),
+            r#"{"aoc":1,"type":"progress","done":7,"total":null}"#
+        );
+    }
+
+    #[test]
+    fn turns_panics_into_errors() {
+        let result: Result<(), Error> = catch(|| panic!("static message"));
+        assert!(matches!(result, Err(Error::Other(message)) if message == "static message"));
+
+        let result: Result<(), Error> = catch(|| panic!("formatted {}", 42));
+        assert!(matches!(result, Err(Error::Other(message)) if message == "formatted 42"));
+
+        let result: Result<(), Error> = catch(|| std::panic::panic_any(7));
+        assert!(matches!(result, Err(Error::Other(message)) if message == "panicked"));
+
+        assert!(matches!(catch(|| Ok(3)), Ok(3)));
+    }
+
+    #[test]
+    fn solve_times_each_step_and_answers_both_parts() {
+        let lines = solve_lines::<Sum>("1\n2\n3\n\n");
+        let prefixes = [
+            r#"{"aoc":1,"type":"timing","stage":"parse","micros":"#,
+            r#"{"aoc":1,"type":"timing","stage":"part1","micros":"#,
+            r#"{"aoc":1,"type":"answer","part":1,"value":"6"}"#,
+            r#"{"aoc":1,"type":"timing","stage":"part2","micros":"#,
+            r#"{"aoc":1,"type":"answer","part":2,"value":"--"}"#,
+        ];
+        assert_eq!(lines.len(), prefixes.len());
+        for (line, prefix) in lines.iter().zip(prefixes) {
+            assert!(
+                line.starts_with(prefix),
+                "{} does not start with {}",
+                line,
+                prefix
+            );
+        }
+    }
+
+    #[test]
+    fn a_failed_parse_answers_neither_part() {
+        let lines = solve_lines::<Sum>("1\nx\n");
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains(r#""stage":"parse""#));
+        assert_eq!(
+            lines[1],
+            r#"{"aoc":1,"type":"answer","part":1,"value":"--"}"#
+        );
+        assert_eq!(
+            lines[2],
+            r#"{"aoc":1,"type":"answer","part":2,"value":"--"}"#
+        );
+    }
+}
@@ -1,9 +1,9 @@
 use color_eyre::eyre::Result;
 use crossterm::event::{self, Event, KeyCode};
 use ratatui::layout::{Alignment, Rect};
-use ratatui::style::{Modifier, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Clear, List, ListItem, ListState};
+use ratatui::widgets::{Clear, List, ListItem, ListState, Wrap};
 use ratatui::{
     DefaultTerminal, Frame,
     layout::{Constraint, Direction, Layout},
@@ -20,24 +20,47 @@ mod colors {
     pub const SNOW_WHITE: Color = Color::Rgb(236, 239, 244); // #ECEFF4
     pub const MUTED_GRAY: Color = Color::Rgb(76, 86, 106); // #4C566A
 }
-use serde::{Deserialize, Serialize};
-use std::fs::{self, File};
+use crate::core::answers::Answers;
+use crate::core::bench::{
+    self, BenchEvent, BenchOptions, Benchmark, Change, Comparison, InputBench,
+};
+use crate::core::calendar;
+use crate::core::client::{self, HttpClient};
+use crate::core::config::{Config, RunOptions};
+use crate::core::discovery::{self, DayEntry, YearEntry};
+use crate::core::download::{self, DownloadOutcome};
+use crate::core::examples;
+use crate::core::puzzle::{self, Article, BlockKind, TextSpan};
+use crate::core::runner::{
+    self, CompileOutcome, ExecError, InputOutput, InputResult, PartResult, RunEvent, RunReport,
+    Verdict,
+};
+use crate::core::scaffold;
+use crate::core::status::DayStatus;
+use crate::core::submit::{self, SubmitOutcome};
+use crate::core::year::{self, DayError, DayRow, YearReport};
+use crate::core::{duration_bar, format_duration};
+use chrono::{Local, Utc};
+use std::collections::HashMap;
 use std::path::Path;
-use std::process::Stdio;
 use std::time::{Duration, Instant};
-use tokio::io::AsyncWriteExt;
-use tokio::process::Command;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
-#[derive(Serialize, Deserialize, Default)]
-pub struct Config {
-    pub current_year: String,
-    pub current_day: String,
-    pub selected_year_index: usize,
-    pub selected_day_index: usize,
-}
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+/// Log and output lines shown per input before the rest is summarized
+const MAX_OUTPUT_LINES: usize = 50;
+/// Width of the per-stage timing bars at 100% of an input's run time
+const STAGE_BAR_WIDTH: usize = 20;
 
 pub enum CurrentScreen {
     Dashboard,
+    /// The day's puzzle description next to the output pane
+    Puzzle,
+    /// Every day of the selected year run at once, as a table
+    Year,
+    /// A grid of the selected year's days with their stars and last run
+    Calendar,
 }
 
 pub enum SelectionLevel {
@@ -45,6 +68,47 @@ pub enum SelectionLevel {
     Day,
 }
 
+/// Progress reported by the background run task to the event loop.
+pub enum RunMessage {
+    Phase(String),
+    Output(Vec<Line<'static>>),
+    Finished(Box<RunReport>),
+}
+
+/// Results of short-lived background jobs such as input downloads, shown in the footer.
+pub enum Notice {
+    Status(String),
+    Error(String),
+    /// `puzzle.html` for this day was (re)downloaded
+    PuzzleUpdated {
+        year: String,
+        day: String,
+    },
+}
+
+/// Progress of the background "run year" task.
+pub enum YearMessage {
+    Day(DayRow),
+    Finished(Duration),
+}
+
+pub struct YearTask {
+    pub handle: JoinHandle<()>,
+    pub rx: mpsc::UnboundedReceiver<YearMessage>,
+    pub started: Instant,
+    /// Days being run, to show the ones still pending
+    pub days: Vec<String>,
+}
+
+pub struct RunningTask {
+    pub handle: JoinHandle<()>,
+    pub rx: mpsc::UnboundedReceiver<RunMessage>,
+    pub started: Instant,
+    pub phase: String,
+    /// Set for benchmarks, whose end adds to the day's history
+    pub benchmark: bool,
+}
+
 pub struct App {
     pub exit: bool,
     pub current_screen: CurrentScreen,
@@ -52,89 +116,229 @@ pub struct App {
     pub show_modal: bool,
     pub selection_level: SelectionLevel,
 
+    /// Year directories and their days as found on disk
+    pub years: Vec<YearEntry>,
     pub available_years: Vec<String>,
     pub selected_year_index: usize,
     pub available_days: Vec<String>,
     pub selected_day_index: usize,
+    /// The last benchmark's real-input median of each day in the modal's
+    /// list, read when the list opens
+    pub day_benches: HashMap<String, String>,
 
     pub current_year: String,
     pub current_day: String,
 
+    pub config: Config,
+    /// The error `config.json` failed to load with; the file is then never
+    /// saved, so fixing it by hand loses nothing
+    pub config_error: Option<String>,
+
     pub run_output: Vec<Line<'static>>,
+    pub running: Option<RunningTask>,
+    pub last_report: Option<RunReport>,
+    /// Benchmarks recorded for the current day, oldest first; reread when the
+    /// day changes or a benchmark finishes
+    pub bench_history: Vec<Benchmark>,
+    /// The current day's files, reread when the day changes or something
+    /// writes to it: a run, a download, a submission
+    pub day_status: DayStatus,
+    pub year_report: Option<YearReport>,
+    pub year_task: Option<YearTask>,
+    /// Day under the calendar's cursor, starting at 1
+    pub calendar_cursor: u32,
+    /// Status of every day of `calendar_year`, in order
+    pub calendar_days: Vec<DayStatus>,
+    pub calendar_year: String,
+    /// Set when a day's files may have changed since `calendar_days` was read
+    pub calendar_stale: bool,
+    pub puzzle_articles: Vec<Article>,
+    pub puzzle_lines: Vec<Line<'static>>,
+    /// Code block picked with `[`/`]` to replace the suggested example
+    pub selected_block: Option<usize>,
+    pub puzzle_scroll: u16,
     pub error_message: Option<String>,
+    pub status_message: Option<String>,
+    /// Part and answer waiting for `y` before they are submitted; a wrong
+    /// guess cannot be taken back and locks the part for a while
+    pub pending_submit: Option<(u8, String)>,
+    pub notice_tx: mpsc::UnboundedSender<Notice>,
+    pub notice_rx: mpsc::UnboundedReceiver<Notice>,
 }
 
 impl App {
     pub fn new() -> Self {
-        let config = Self::load_config();
-        Self {
+        let (config, config_error) = match Config::load() {
+            Ok(config) => (config, None),
+            Err(e) => (
+                Config::default(),
+                Some(format!("{:#}; settings are not saved until it is fixed", e)),
+            ),
+        };
+        let (notice_tx, notice_rx) = mpsc::unbounded_channel();
+        let mut app = Self {
             exit: false,
             show_modal: false,
             selection_level: SelectionLevel::Year,
             current_screen: CurrentScreen::Dashboard,
-            available_years: vec!["2025".to_string(), "2024".to_string(), "2023".to_string()],
-            available_days: (1..=25).map(|d| format!("{:02}", d)).collect(),
+            years: Vec::new(),
+            available_years: Vec::new(),
+            available_days: Vec::new(),
+            day_benches: HashMap::new(),
             run_output: vec![Line::from(Span::styled(
                 "Press 'r' to run solution",
                 Style::default().fg(colors::MUTED_GRAY),
             ))],
+            running: None,
+            last_report: None,
+            bench_history: Vec::new(),
+            day_status: DayStatus::default(),
+            year_report: None,
+            year_task: None,
+            calendar_cursor: 1,
+            calendar_days: Vec::new(),
+            calendar_year: String::new(),
+            calendar_stale: true,
+            puzzle_articles: Vec::new(),
+            puzzle_lines: Vec::new(),
+            selected_block: None,
+            puzzle_scroll: 0,
             error_message: None,
+            status_message: None,
+            pending_submit: None,
+            notice_tx,
+            notice_rx,
             // From config
             current_year: if config.current_year.is_empty() {
                 "2025".to_string()
             } else {
-                config.current_year
+                config.current_year.clone()
             },
             current_day: if config.current_day.is_empty() {
                 "01".to_string()
             } else {
-                config.current_day
+                config.current_day.clone()
             },
             selected_year_index: config.selected_year_index,
             selected_day_index: config.selected_day_index,
+            config,
+            config_error,
+        };
+        app.refresh_years();
+        app.select_today();
+        app.load_bench_history();
+        app.load_status();
+        app
+    }
+
+    /// Rescans the tree for year directories. The running event's year is
+    /// listed even before its directory exists so its days can be scaffolded.
+    fn refresh_years(&mut self) {
+        self.years = discovery::discover(Path::new("."));
+        self.available_years = self.years.iter().map(|year| year.name.clone()).collect();
+        let (event, _) = calendar::puzzle_today(Utc::now()).unwrap_or_default();
+        if !event.is_empty() && !self.available_years.contains(&event) {
+            self.available_years.insert(0, event);
+        }
+        if !self.available_years.contains(&self.current_year) {
+            self.available_years.push(self.current_year.clone());
+        }
+        self.selected_year_index = self
+            .available_years
+            .iter()
+            .position(|year| *year == self.current_year)
+            .unwrap_or_default();
+        self.load_year_days();
+    }
+
+    /// Lists the days of the current year's event (12 from 2025 on, 25 before).
+    fn load_year_days(&mut self) {
+        let count = discovery::event_year(&self.current_year)
+            .map(calendar::days_in_year)
+            .unwrap_or(25);
+        self.available_days = (1..=count).map(|d| format!("{:02}", d)).collect();
+        self.selected_day_index = self
+            .available_days
+            .iter()
+            .position(|day| *day == self.current_day)
+            .unwrap_or_default();
+    }
+
+    fn load_bench_history(&mut self) {
+        self.bench_history = bench::history(&self.current_year, &self.current_day);
+    }
+
+    /// Rereads the current day's status and marks the calendar for rereading.
+    fn load_status(&mut self) {
+        self.day_status = DayStatus::read(&self.current_year, &self.current_day);
+        self.calendar_stale = true;
+    }
+
+    /// Reads the last benchmark of every listed day for the modal.
+    fn load_day_benches(&mut self) {
+        self.day_benches = self
+            .available_days
+            .iter()
+            .filter_map(|day| {
+                let benchmark = Benchmark::load(&self.current_year, day)?;
+                let median = benchmark.real_input()?.total.median;
+                Some((day.clone(), bench::format_ms(median)))
+            })
+            .collect();
+    }
+
+    /// The discovered entry for the current day, in whatever layout it uses.
+    fn current_entry(&self) -> Option<&DayEntry> {
+        let day = self.current_day.parse().ok()?;
+        self.years
+            .iter()
+            .find(|year| year.name == self.current_year)?
+            .day(day)
+    }
+
+    /// During an event, starts on the puzzle that unlocked last (US Eastern midnight).
+    fn select_today(&mut self) {
+        let Some((year, day)) = calendar::puzzle_today(Utc::now()) else {
+            return;
+        };
+        if let Some(index) = self.available_years.iter().position(|y| *y == year) {
+            self.selected_year_index = index;
         }
+        self.current_year = year;
+        self.current_day = day;
+        self.load_year_days();
     }
 
     pub async fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
         while !self.exit {
+            self.poll_run();
+            self.poll_year();
+            self.poll_notices();
+            self.refresh_calendar();
             terminal.draw(|frame| self.draw(frame))?;
-            self.handle_events(terminal).await?;
+            self.handle_events().await?;
             self.save_config();
         }
-        Ok(())
-    }
-
-    fn load_config() -> Config {
-        fs::read_to_string("config.json")
-            .ok()
-            .and_then(|s| serde_json::from_str(&s).ok())
-            .unwrap_or_default()
-    }
-
-    fn try_io<T>(&mut self, result: std::io::Result<T>, context: &str) -> Option<T> {
-        match result {
-            Ok(v) => Some(v),
-            Err(e) => {
-                self.error_message = Some(format!("{}: {}", context, e));
-                None
-            }
+        if let Some(task) = self.running.take() {
+            task.handle.abort();
         }
+        if let Some(task) = self.year_task.take() {
+            task.handle.abort();
+        }
+        Ok(())
     }
 
     fn save_config(&mut self) {
-        let config = Config {
-            current_year: self.current_year.clone(),
-            current_day: self.current_day.clone(),
-            selected_year_index: self.selected_year_index,
-            selected_day_index: self.selected_day_index,
-        };
-        self.try_io(
-            fs::write(
-                "config.json",
-                serde_json::to_string_pretty(&config).unwrap(),
-            ),
-            "Failed to save config",
-        );
+        self.config.current_year = self.current_year.clone();
+        self.config.current_day = self.current_day.clone();
+        self.config.selected_year_index = self.selected_year_index;
+        self.config.selected_day_index = self.selected_day_index;
+        if self.config_error.is_some() {
+            return;
+        }
+        if let Err(e) = self.config.save() {
+            self.error_message = Some(format!("{:#}", e));
+        }
     }
 
     fn draw(&self, frame: &mut Frame) {
@@ -142,6 +346,15 @@ impl App {
             CurrentScreen::Dashboard => {
                 self.draw_dashboard(frame);
             }
+            CurrentScreen::Puzzle => {
+                self.draw_puzzle_screen(frame);
+            }
+            CurrentScreen::Year => {
+                self.draw_year_screen(frame);
+            }
+            CurrentScreen::Calendar => {
+                self.draw_calendar_screen(frame);
+            }
         }
 
         if self.show_modal {
@@ -166,9 +379,51 @@ impl App {
             ),
         };
 
+        let now = Utc::now();
+        let year = self
+            .years
+            .iter()
+            .find(|year| year.name == self.current_year);
         let list_items: Vec<ListItem> = items
             .iter()
-            .map(|item| ListItem::new(item.as_str()).style(Style::default().fg(colors::SNOW_WHITE)))
+            .map(|item| match self.selection_level {
+                SelectionLevel::Day if !calendar::is_unlocked(&self.current_year, item, now) => {
+                    ListItem::new(format!("{}  locked", item))
+                        .style(Style::default().fg(colors::MUTED_GRAY))
+                }
+                SelectionLevel::Day => {
+                    let entry = item
+                        .parse()
+                        .ok()
+                        .and_then(|day| year.and_then(|year| year.day(day)));
+                    let bench = self
+                        .day_benches
+                        .get(item)
+                        .map(|median| format!("  ⏱ {}", median))
+                        .unwrap_or_default();
+                    match entry {
+                        Some(entry) => ListItem::new(format!(
+                            "{}  ● {} {}{}",
+                            item,
+                            entry.layout.name(),
+                            entry.sources.join(" "),
+                            bench
+                        ))
+                        .style(Style::default().fg(colors::SNOW_WHITE)),
+                        None => ListItem::new(item.as_str())
+                            .style(Style::default().fg(colors::SNOW_WHITE)),
+                    }
+                }
+                SelectionLevel::Year => {
+                    let label = match self.years.iter().find(|year| year.name == *item) {
+                        Some(year) => {
+                            format!("{}  ({}/{} days)", item, year.days.len(), year.day_count())
+                        }
+                        None => format!("{}  (new)", item),
+                    };
+                    ListItem::new(label).style(Style::default().fg(colors::SNOW_WHITE))
+                }
+            })
             .collect();
 
         let list = List::new(list_items)
@@ -244,33 +499,385 @@ impl App {
         self.draw_footer(frame, main_chunks[2]);
     }
 
-    fn draw_header(&self, frame: &mut Frame, area: Rect) {
-        let title = Paragraph::new("Advent of Code CLI")
-            .style(
+    fn draw_year_screen(&self, frame: &mut Frame) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(10),   // Table
+                Constraint::Length(3), // Footer
+            ])
+            .split(frame.area());
+
+        self.draw_header(frame, main_chunks[0]);
+
+        let muted = Style::default().fg(colors::MUTED_GRAY);
+        let mut lines = vec![Line::from(Span::styled(
+            year::header(),
+            muted.add_modifier(Modifier::BOLD),
+        ))];
+        if let Some(report) = &self.year_report {
+            for row in &report.rows {
+                lines.push(Self::year_row_line(row));
+            }
+        }
+        if let Some(task) = &self.year_task {
+            let finished = |day: &String| {
+                self.year_report
+                    .as_ref()
+                    .is_some_and(|report| report.rows.iter().any(|row| row.day == *day))
+            };
+            let elapsed = task.started.elapsed();
+            let frame_index = (elapsed.as_millis() / 100) as usize % SPINNER_FRAMES.len();
+            for day in task.days.iter().filter(|day| !finished(day)) {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "{:<w$}{} running",
+                        day,
+                        SPINNER_FRAMES[frame_index],
+                        w = year::COLUMNS[0]
+                    ),
+                    Style::default().fg(colors::AURORA_YELLOW),
+                )));
+            }
+        }
+        if let Some(report) = &self.year_report {
+            lines.push(Line::from(""));
+            let wall = match (report.wall_time, &self.year_task) {
+                (Some(wall), _) => format!(" (wall clock {})", format_duration(wall)),
+                (None, Some(task)) => {
+                    format!(" (running for {})", format_duration(task.started.elapsed()))
+                }
+                (None, None) => String::new(),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "Total {} across {} days",
+                        format_duration(report.total_run_time()),
+                        report.rows.len()
+                    ),
+                    Style::default()
+                        .fg(colors::SNOW_WHITE)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(wall, muted),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Tests: ✓ pass  ✗ fail  ? no expected answer  · none    ★ confirmed answer    r rerun · y/Esc back",
+            muted,
+        )));
+
+        let year = self
+            .year_report
+            .as_ref()
+            .map_or(self.current_year.as_str(), |report| report.year.as_str());
+        let table = Paragraph::new(lines).block(
+            Block::default()
+                .title(Span::styled(
+                    format!(" Year {} ", year),
+                    Style::default()
+                        .fg(colors::FROST_CYAN)
+                        .add_modifier(Modifier::BOLD),
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors::FROST_CYAN)),
+        );
+        frame.render_widget(table, main_chunks[1]);
+        self.draw_footer(frame, main_chunks[2]);
+    }
+
+    /// Columns of the calendar grid: 5×5 for 25 days, 4×3 for 12.
+    fn calendar_columns(&self) -> u32 {
+        if self.calendar_days.len() <= 12 { 4 } else { 5 }
+    }
+
+    fn draw_calendar_screen(&self, frame: &mut Frame) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(10),   // Grid
+                Constraint::Length(3), // Footer
+            ])
+            .split(frame.area());
+
+        self.draw_header(frame, main_chunks[0]);
+
+        let stars: usize = self
+            .calendar_days
+            .iter()
+            .map(|status| status.stars as usize)
+            .sum();
+        let block = Block::default()
+            .title(Span::styled(
+                format!(
+                    " Calendar {} · {}/{} ★ · arrows move · Enter open · Esc back ",
+                    self.calendar_year,
+                    stars,
+                    self.calendar_days.len() * 2
+                ),
                 Style::default()
                     .fg(colors::FROST_CYAN)
                     .add_modifier(Modifier::BOLD),
-            )
-            .alignment(Alignment::Center)
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::FROST_CYAN));
+        let grid_area = block.inner(main_chunks[1]);
+        frame.render_widget(block, main_chunks[1]);
+
+        let columns = self.calendar_columns();
+        let rows = (self.calendar_days.len() as u32).div_ceil(columns);
+        let row_areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints((0..rows).map(|_| Constraint::Ratio(1, rows)))
+            .split(grid_area);
+        let now = Utc::now();
+        for (index, status) in self.calendar_days.iter().enumerate() {
+            let index = index as u32;
+            let cell_areas = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints((0..columns).map(|_| Constraint::Ratio(1, columns)))
+                .split(row_areas[(index / columns) as usize]);
+            self.draw_calendar_cell(
+                frame,
+                cell_areas[(index % columns) as usize],
+                index + 1,
+                status,
+                now,
+            );
+        }
+        self.draw_footer(frame, main_chunks[2]);
+    }
+
+    fn draw_calendar_cell(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        day: u32,
+        status: &DayStatus,
+        now: chrono::DateTime<Utc>,
+    ) {
+        let day_name = format!("{:02}", day);
+        let muted = Style::default().fg(colors::MUTED_GRAY);
+        let unlocks_at = calendar::unlock_time(&self.calendar_year, &day_name)
+            .filter(|unlocks_at| now < *unlocks_at);
+
+        let lines = match unlocks_at {
+            Some(unlocks_at) => vec![
+                Line::from(Span::styled("locked", muted)),
+                Line::from(Span::styled(
+                    calendar::format_countdown(unlocks_at - now),
+                    muted,
+                )),
+            ],
+            None => {
+                let stars = Line::from(vec![
+                    Span::styled(
+                        "★".repeat(status.stars as usize),
+                        Style::default().fg(colors::AURORA_YELLOW),
+                    ),
+                    Span::styled("☆".repeat(2 - status.stars as usize), muted),
+                ]);
+                let (solution_text, solution_color) = if status.has_solution {
+                    ("● solution", colors::AURORA_GREEN)
+                } else {
+                    ("○ no solution", colors::MUTED_GRAY)
+                };
+                let last_run = status.last_run.as_ref();
+                let (tests_text, tests_color) = match last_run.map(|run| run.tests) {
+                    Some(Some(Verdict::Pass)) => ("✓ tests", colors::AURORA_GREEN),
+                    Some(Some(Verdict::Fail)) => ("✗ tests", colors::AURORA_RED),
+                    Some(Some(Verdict::Unknown)) => ("? tests", colors::AURORA_YELLOW),
+                    Some(None) => ("· no tests", colors::MUTED_GRAY),
+                    None => ("· not run", colors::MUTED_GRAY),
+                };
+                vec![
+                    stars,
+                    Line::from(Span::styled(
+                        solution_text,
+                        Style::default().fg(solution_color),
+                    )),
+                    Line::from(Span::styled(tests_text, Style::default().fg(tests_color))),
+                    Line::from(Span::styled(
+                        last_run
+                            .and_then(|run| run.run_time())
+                            .map(|time| format!("⏱ {}", format_duration(time)))
+                            .unwrap_or_default(),
+                        muted,
+                    )),
+                ]
+            }
+        };
+
+        let border = if day == self.calendar_cursor {
+            Style::default()
+                .fg(colors::AURORA_YELLOW)
+                .add_modifier(Modifier::BOLD)
+        } else if self.calendar_year == self.current_year && day_name == self.current_day {
+            Style::default().fg(colors::FROST_CYAN)
+        } else {
+            muted
+        };
+        let cell = Paragraph::new(lines).block(
+            Block::default()
+                .title(Span::styled(format!(" {} ", day_name), border))
+                .borders(Borders::ALL)
+                .border_style(border),
+        );
+        frame.render_widget(cell, area);
+    }
+
+    fn year_row_line(row: &DayRow) -> Line<'static> {
+        let day = Span::styled(
+            format!("{:<w$}", row.day, w = year::COLUMNS[0]),
+            Style::default().fg(colors::SNOW_WHITE),
+        );
+        if let Some(DayError::Compile(e) | DayError::Run(e)) = &row.error
+            && row.parts.iter().all(|part| part.answer.is_none())
+        {
+            return Line::from(vec![
+                day,
+                Span::styled(format!("✗ {}", e), Style::default().fg(colors::AURORA_RED)),
+            ]);
+        }
+        let mut spans = vec![day];
+        for (part, width) in row.parts.iter().zip(&year::COLUMNS[1..3]) {
+            let tests_color = match part.tests {
+                Some(Verdict::Pass) => colors::AURORA_GREEN,
+                Some(Verdict::Fail) => colors::AURORA_RED,
+                Some(Verdict::Unknown) => colors::AURORA_YELLOW,
+                None => colors::MUTED_GRAY,
+            };
+            let answer_color = match part.verdict {
+                Verdict::Pass => colors::AURORA_GREEN,
+                Verdict::Fail => colors::AURORA_RED,
+                Verdict::Unknown => colors::SNOW_WHITE,
+            };
+            spans.push(Span::styled(
+                format!("{} ", part.tests_icon()),
+                Style::default().fg(tests_color),
+            ));
+            spans.push(Span::styled(
+                format!("{:<w$}", part.answer_text(), w = width - 2),
+                Style::default().fg(answer_color),
+            ));
+        }
+        spans.push(Span::styled(
+            format!(
+                "{:>w$}",
+                row.run_time.map(format_duration).unwrap_or_default(),
+                w = year::COLUMNS[3]
+            ),
+            Style::default().fg(colors::MUTED_GRAY),
+        ));
+        Line::from(spans)
+    }
+
+    /// The puzzle screen's header, puzzle pane, output pane and footer.
+    fn puzzle_screen_areas(area: Rect) -> [Rect; 4] {
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(10),   // Content
+                Constraint::Length(3), // Footer
+            ])
+            .split(area);
+
+        // Content: Puzzle | Output
+        let content_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(60), // Puzzle
+                Constraint::Percentage(40), // Output
+            ])
+            .split(main_chunks[1]);
+
+        [
+            main_chunks[0],
+            content_chunks[0],
+            content_chunks[1],
+            main_chunks[2],
+        ]
+    }
+
+    fn draw_puzzle_screen(&self, frame: &mut Frame) {
+        let [header, puzzle_area, output, footer] = Self::puzzle_screen_areas(frame.area());
+
+        self.draw_header(frame, header);
+
+        let puzzle = Paragraph::new(self.puzzle_lines.clone())
+            .wrap(Wrap { trim: false })
+            .scroll((self.puzzle_scroll, 0))
             .block(
                 Block::default()
+                    .title(Span::styled(
+                        match self.selected_block {
+                            Some(index) => format!(
+                                " Puzzle {}/{} · block {}/{} · t use as example · [/] pick ",
+                                self.current_year,
+                                self.current_day,
+                                index + 1,
+                                examples::code_blocks(&self.puzzle_articles).len()
+                            ),
+                            None => format!(
+                                " Puzzle {}/{} · j/k scroll · [/] pick example · f refresh · v back ",
+                                self.current_year, self.current_day
+                            ),
+                        },
+                        Style::default()
+                            .fg(colors::FROST_CYAN)
+                            .add_modifier(Modifier::BOLD),
+                    ))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(colors::FROST_CYAN)),
-            );
+            )
+            .style(Style::default().fg(colors::SNOW_WHITE));
+        frame.render_widget(puzzle, puzzle_area);
+
+        self.draw_output(frame, output);
+        self.draw_footer(frame, footer);
+    }
+
+    fn draw_header(&self, frame: &mut Frame, area: Rect) {
+        let now = Utc::now();
+        let (year, day, unlocks_at) = calendar::next_unlock(now);
+        let title = Paragraph::new(Line::from(vec![
+            Span::raw("Advent of Code CLI"),
+            Span::styled(
+                format!(
+                    "  ·  {}/{} unlocks in {}",
+                    year,
+                    day,
+                    calendar::format_countdown(unlocks_at - now)
+                ),
+                Style::default()
+                    .fg(colors::MUTED_GRAY)
+                    .remove_modifier(Modifier::BOLD),
+            ),
+        ]))
+        .style(
+            Style::default()
+                .fg(colors::FROST_CYAN)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors::FROST_CYAN)),
+        );
         frame.render_widget(title, area);
     }
 
     fn draw_sidebar(&self, frame: &mut Frame, area: Rect) {
-        let base = format!("{}/{}", self.current_year, self.current_day);
+        let options = self.run_options();
+        let limits = options.limits;
 
-        // Check file existence for status indicators
-        let has_solution = Path::new(&format!("{}/run.rs", base)).exists();
-        let has_test = fs::read_to_string(format!("{}/test.txt", base))
-            .map(|s| !s.trim().is_empty())
-            .unwrap_or(false);
-        let has_input = fs::read_to_string(format!("{}/input.txt", base))
-            .map(|s| !s.trim().is_empty())
-            .unwrap_or(false);
+        let status = &self.day_status;
 
         let indicator = |ready: bool| {
             if ready {
@@ -280,7 +887,7 @@ impl App {
             }
         };
 
-        let lines = vec![
+        let mut lines = vec![
             Line::from(Span::styled(
                 " CONFIG",
                 Style::default()
@@ -289,6 +896,17 @@ impl App {
             )),
             Line::from(format!("  Year: {}", self.current_year)),
             Line::from(format!("  Day:  {}", self.current_day)),
+            Line::from(format!(
+                "  Layout: {}",
+                self.current_entry()
+                    .map(|entry| entry.layout.name())
+                    .unwrap_or("none")
+            )),
+            Line::from(format!("  Build: {}", options.profile.name())),
+            Line::from(Span::styled(
+                format!("  Limit: {}s / {}MB", limits.timeout_secs, limits.memory_mb),
+                Style::default().fg(colors::MUTED_GRAY),
+            )),
             Line::from(""),
             Line::from(Span::styled(
                 " STATUS",
@@ -298,19 +916,22 @@ impl App {
             )),
             Line::from(vec![
                 Span::raw("  "),
-                indicator(has_solution),
+                indicator(status.has_solution),
                 Span::raw(" Solution"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                indicator(has_test),
+                indicator(status.has_test),
                 Span::raw(" Test Input"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
-                indicator(has_input),
+                indicator(status.has_input),
                 Span::raw(" Real Input"),
             ]),
+        ];
+        lines.extend(Self::bench_summary(&self.bench_history));
+        lines.extend([
             Line::from(""),
             Line::from(Span::styled(
                 " KEYBINDS",
@@ -326,11 +947,47 @@ impl App {
                 "  r  Run",
                 Style::default().fg(colors::SNOW_WHITE),
             )),
+            Line::from(Span::styled(
+                "  b  Benchmark",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  g  Calendar",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  y  Run year",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  x  Cancel run",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  a  Accept answers",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  1  Submit part 1",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  2  Submit part 2",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  v  View puzzle",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
+            Line::from(Span::styled(
+                "  p  Build profile",
+                Style::default().fg(colors::SNOW_WHITE),
+            )),
             Line::from(Span::styled(
                 "  q  Quit",
                 Style::default().fg(colors::SNOW_WHITE),
             )),
-        ];
+        ]);
 
         let sidebar = Paragraph::new(lines).block(
             Block::default()
@@ -340,8 +997,98 @@ impl App {
         frame.render_widget(sidebar, area);
     }
 
+    /// The sidebar's view of the day's last benchmark: the real input's median
+    /// and spread, each stage's median, and how the total moved against the
+    /// benchmark before it.
+    fn bench_summary(history: &[Benchmark]) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                " BENCHMARK",
+                Style::default()
+                    .fg(colors::FROST_CYAN)
+                    .add_modifier(Modifier::BOLD),
+            )),
+        ];
+        let Some((benchmark, earlier)) = history.split_last() else {
+            lines.push(Line::from(Span::styled(
+                "  None yet, press 'b'",
+                Style::default().fg(colors::MUTED_GRAY),
+            )));
+            return lines;
+        };
+        let Some(input) = benchmark.real_input().or(benchmark.inputs.last()) else {
+            return lines;
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {} ", input.input)),
+            Span::styled(
+                bench::format_ms(input.total.median),
+                Style::default().fg(colors::SNOW_WHITE),
+            ),
+            Span::styled(
+                format!(" ±{}", bench::format_ms(input.total.stddev)),
+                Style::default().fg(colors::MUTED_GRAY),
+            ),
+        ]));
+        for stage in &input.stages {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "    {:<6} {}",
+                    stage.stage,
+                    bench::format_ms(stage.stats.median)
+                ),
+                Style::default().fg(colors::MUTED_GRAY),
+            )));
+        }
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {}, {} runs, {}",
+                benchmark.profile.name(),
+                benchmark.runs,
+                benchmark.commit_label()
+            ),
+            Style::default().fg(colors::MUTED_GRAY),
+        )));
+        let total =
+            bench::baseline(earlier, benchmark.profile, &input.input).and_then(|baseline| {
+                bench::compare(baseline, benchmark, input)
+                    .into_iter()
+                    .next()
+            });
+        if let Some(total) = total {
+            let change = total.change();
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {} {:+.1}% {}",
+                    change.icon(),
+                    total.relative() * 100.0,
+                    change.label()
+                ),
+                Style::default().fg(Self::change_color(change)),
+            )));
+        }
+        lines
+    }
+
     fn draw_output(&self, frame: &mut Frame, area: Rect) {
-        let output = Paragraph::new(self.run_output.clone())
+        let mut lines = self.run_output.clone();
+        if let Some(task) = &self.running {
+            let elapsed = task.started.elapsed();
+            let frame_index = (elapsed.as_millis() / 100) as usize % SPINNER_FRAMES.len();
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} {}...", SPINNER_FRAMES[frame_index], task.phase),
+                    Style::default().fg(colors::AURORA_YELLOW),
+                ),
+                Span::styled(
+                    format!(" ({})", format_duration(elapsed)),
+                    Style::default().fg(colors::MUTED_GRAY),
+                ),
+            ]));
+        }
+
+        let output = Paragraph::new(lines)
             .block(
                 Block::default()
                     .title(Span::styled(
@@ -358,14 +1105,40 @@ impl App {
     }
 
     fn draw_footer(&self, frame: &mut Frame, area: Rect) {
-        let (content, style) = match &self.error_message {
-            Some(err) => (
+        let busy = self.running.is_some() || self.year_task.is_some();
+        if let Some((part, answer)) = &self.pending_submit {
+            let prompt = format!(
+                " Submit {} as the part {} answer? Press 'y' to confirm, any other key cancels",
+                answer, part
+            );
+            let footer = Paragraph::new(prompt)
+                .style(Style::default().fg(colors::AURORA_YELLOW))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(colors::FROST_CYAN)),
+                );
+            frame.render_widget(footer, area);
+            return;
+        }
+        // A broken config.json stays visible, unlike errors cleared by a key
+        let error = self.error_message.as_ref().or(self.config_error.as_ref());
+        let (content, style) = match (error, busy, &self.status_message) {
+            (Some(err), _, _) => (
                 format!(" [ERROR] {}", err),
                 Style::default()
                     .fg(colors::SNOW_WHITE)
                     .bg(colors::AURORA_RED),
             ),
-            None => (
+            (None, true, _) => (
+                String::from(" Running... press 'x' to cancel"),
+                Style::default().fg(colors::AURORA_YELLOW),
+            ),
+            (None, false, Some(status)) => (
+                format!(" {}", status),
+                Style::default().fg(colors::AURORA_GREEN),
+            ),
+            (None, false, None) => (
                 String::from(" Ready"),
                 Style::default().fg(colors::MUTED_GRAY),
             ),
@@ -414,448 +1187,1151 @@ impl App {
             match &self.selection_level {
                 SelectionLevel::Year => {
                     self.current_year = self.available_years[self.selected_year_index].clone();
+                    self.load_year_days();
+                    self.load_bench_history();
+                    self.load_status();
+                    self.load_day_benches();
                     self.selection_level = SelectionLevel::Day;
                 }
                 SelectionLevel::Day => {
-                    self.current_day = self.available_days[self.selected_day_index].clone();
-                    self.show_modal = false;
-                    self.run_output = vec![Line::from(Span::styled(
-                        "Press 'r' to run solution",
-                        Style::default().fg(colors::MUTED_GRAY),
-                    ))];
-                    self.generate_missing_structure();
+                    let day = self.available_days[self.selected_day_index].clone();
+                    self.open_day(day);
                 }
             }
         }
     }
 
-    async fn run_solution(&mut self) -> Result<()> {
-        let base = format!("{}/{}", self.current_year, self.current_day);
-        let source_path = format!("{}/run.rs", base);
-        let bin_path = format!("/tmp/aoc_runner_{}_{}", self.current_year, self.current_day);
-
-        let mut output_lines: Vec<Line<'static>> = Vec::new();
+    /// Makes `day` of the current year the dashboard's day, scaffolding and
+    /// downloading it as needed. Returns `false` for a locked day.
+    fn open_day(&mut self, day: String) -> bool {
+        // Scaffolding and downloading a locked day only produces empty files
+        if let Some(unlocks_at) = calendar::unlock_time(&self.current_year, &day)
+            && Utc::now() < unlocks_at
+        {
+            self.error_message = Some(format!(
+                "{}/{} is locked until {}",
+                self.current_year,
+                day,
+                unlocks_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+            ));
+            return false;
+        }
+        if let Some(index) = self.available_days.iter().position(|d| *d == day) {
+            self.selected_day_index = index;
+        }
+        self.current_day = day;
+        self.show_modal = false;
+        self.load_bench_history();
+        // A run for the previous day would keep appending to the new pane
+        if let Some(task) = self.running.take() {
+            task.handle.abort();
+        }
+        self.run_output = vec![Line::from(Span::styled(
+            "Press 'r' to run solution",
+            Style::default().fg(colors::MUTED_GRAY),
+        ))];
+        self.generate_missing_structure();
+        self.load_status();
+        self.start_download();
+        if matches!(self.current_screen, CurrentScreen::Puzzle) {
+            self.open_puzzle();
+        }
+        true
+    }
 
-        // Compile with timing
-        let compile_start = Instant::now();
-        let compile_cmd = Command::new("rustc")
-            .arg(&source_path)
-            .arg("-o")
-            .arg(&bin_path)
-            .output()
-            .await;
-        let compile_time = compile_start.elapsed();
+    fn run_options(&self) -> RunOptions {
+        self.config
+            .run_options(&self.current_year, &self.current_day)
+    }
 
-        match compile_cmd {
-            Err(e) => {
-                output_lines.push(Line::from(vec![
-                    Span::styled("✗ ", Style::default().fg(colors::AURORA_RED)),
-                    Span::styled(
-                        format!("Failed to start compiler: {}", e),
-                        Style::default().fg(colors::AURORA_RED),
-                    ),
-                ]));
-                self.run_output = output_lines;
-                return Ok(());
-            }
-            Ok(output) => {
-                if !output.status.success() {
-                    output_lines.push(Line::from(vec![
-                        Span::styled("✗ ", Style::default().fg(colors::AURORA_RED)),
-                        Span::styled(
-                            "Compilation Failed",
-                            Style::default()
-                                .fg(colors::AURORA_RED)
-                                .add_modifier(Modifier::BOLD),
-                        ),
-                    ]));
-                    output_lines.push(Line::from(""));
-                    for line in String::from_utf8_lossy(&output.stderr).lines() {
-                        output_lines.push(Line::from(Span::styled(
-                            line.to_string(),
-                            Style::default().fg(colors::AURORA_RED),
-                        )));
-                    }
-                    self.run_output = output_lines;
-                    return Ok(());
-                }
-            }
+    /// Spawns `run_solution` on a background task so the event loop keeps drawing.
+    fn start_run(&mut self) {
+        if self.running.is_some() {
+            return;
         }
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(Self::run_solution(
+            self.current_year.clone(),
+            self.current_day.clone(),
+            self.run_options(),
+            tx,
+        ));
+        self.run_output = Vec::new();
+        self.running = Some(RunningTask {
+            handle,
+            rx,
+            started: Instant::now(),
+            phase: String::from("Starting"),
+            benchmark: false,
+        });
+    }
 
-        // Compilation succeeded
-        output_lines.push(Line::from(vec![
-            Span::styled("✓ ", Style::default().fg(colors::AURORA_GREEN)),
-            Span::styled("Compiled", Style::default().fg(colors::AURORA_GREEN)),
-            Span::styled(
-                format!(" ({})", Self::format_duration(compile_time)),
-                Style::default().fg(colors::MUTED_GRAY),
-            ),
-        ]));
-        output_lines.push(Line::from(""));
-
-        // Load expected solutions
-        let solution_1 = fs::read_to_string(format!("{}/solution_1.txt", base))
-            .ok()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty());
-        let solution_2 = fs::read_to_string(format!("{}/solution_2.txt", base))
-            .ok()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty());
-
-        let mut has_any_input = false;
-
-        // Run for each input file
-        for input_name in ["test", "input"] {
-            let input_path = format!("{}/{}.txt", base, input_name);
-            let input_content = match fs::read_to_string(&input_path) {
-                Ok(content) if !content.trim().is_empty() => content,
-                _ => continue,
-            };
+    /// Spawns a benchmark of the day on the background task slot a run uses,
+    /// so it shows the same spinner and can be cancelled with 'x'.
+    fn start_benchmark(&mut self) {
+        if self.running.is_some() {
+            return;
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = tokio::spawn(Self::run_benchmark(
+            self.current_year.clone(),
+            self.current_day.clone(),
+            self.config.bench,
+            self.run_options(),
+            tx,
+        ));
+        self.run_output = Vec::new();
+        self.running = Some(RunningTask {
+            handle,
+            rx,
+            started: Instant::now(),
+            phase: String::from("Starting benchmark"),
+            benchmark: true,
+        });
+    }
 
-            has_any_input = true;
+    async fn run_benchmark(
+        year: String,
+        day: String,
+        bench: BenchOptions,
+        options: RunOptions,
+        tx: mpsc::UnboundedSender<RunMessage>,
+    ) {
+        let mut on_event = |event: BenchEvent<'_>| {
+            let message = match event {
+                BenchEvent::Phase(phase) => RunMessage::Phase(phase),
+                BenchEvent::Compiled(backend, profile) => RunMessage::Output(vec![
+                    Line::from(vec![
+                        Span::styled("✓ ", Style::default().fg(colors::AURORA_GREEN)),
+                        Span::styled(
+                            format!("Built ({}, {})", profile.name(), backend.name()),
+                            Style::default().fg(colors::AURORA_GREEN),
+                        ),
+                        Span::styled(
+                            format!(" · {} runs after {} warm-up", bench.runs, bench.warmup),
+                            Style::default().fg(colors::MUTED_GRAY),
+                        ),
+                    ]),
+                    Line::from(""),
+                ]),
+                BenchEvent::Input {
+                    input,
+                    baseline,
+                    comparisons,
+                } => RunMessage::Output(Self::bench_lines(input, baseline, &comparisons)),
+            };
+            let _ = tx.send(message);
+        };
+        if let Err(e) = bench::benchmark_day(&year, &day, bench, options, &mut on_event).await {
+            let _ = tx.send(RunMessage::Output(vec![Line::from(vec![
+                Span::styled("✗ ", Style::default().fg(colors::AURORA_RED)),
+                Span::styled(format!("{:#}", e), Style::default().fg(colors::AURORA_RED)),
+            ])]));
+        }
+    }
 
-            // Section header
-            let header_style = Style::default()
+    fn bench_lines(
+        input: &InputBench,
+        baseline: Option<&Benchmark>,
+        comparisons: &[Comparison],
+    ) -> Vec<Line<'static>> {
+        let mut lines = vec![Line::from(Span::styled(
+            format!("─── BENCH · {} ───", input.input),
+            Style::default()
                 .fg(colors::FROST_CYAN)
-                .add_modifier(Modifier::BOLD);
-            let header_text = if input_name == "test" {
-                "TEST"
+                .add_modifier(Modifier::BOLD),
+        ))];
+        for (index, row) in input.table().into_iter().enumerate() {
+            let color = if index == 0 {
+                colors::MUTED_GRAY
             } else {
-                "INPUT"
+                colors::SNOW_WHITE
             };
-            output_lines.push(Line::from(Span::styled(
-                format!("─── {} ───", header_text),
-                header_style,
+            lines.push(Line::from(Span::styled(
+                format!("  {}", row),
+                Style::default().fg(color),
+            )));
+        }
+        if let Some(baseline) = baseline {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  vs {} ({})",
+                    baseline.commit_label(),
+                    baseline
+                        .recorded_at
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                ),
+                Style::default().fg(colors::MUTED_GRAY),
             )));
+            for comparison in comparisons {
+                let change = comparison.change();
+                lines.push(Line::from(Span::styled(
+                    format!("  {} {}", change.icon(), comparison.summary()),
+                    Style::default().fg(Self::change_color(change)),
+                )));
+            }
+        }
+        lines.push(Line::from(""));
+        lines
+    }
+
+    fn change_color(change: Change) -> Color {
+        match change {
+            Change::Slower => colors::AURORA_RED,
+            Change::Faster => colors::AURORA_GREEN,
+            Change::Unchanged => colors::MUTED_GRAY,
+        }
+    }
+
+    /// Kills the running solution. Dropping the task drops the child process,
+    /// which is spawned with `kill_on_drop`.
+    fn cancel_run(&mut self) {
+        if let Some(task) = self.running.take() {
+            task.handle.abort();
+            self.run_output.push(Line::from(vec![
+                Span::styled("✗ ", Style::default().fg(colors::AURORA_RED)),
+                Span::styled(
+                    format!(
+                        "Cancelled after {}",
+                        format_duration(task.started.elapsed())
+                    ),
+                    Style::default()
+                        .fg(colors::AURORA_RED)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+        }
+        // Dropping the year's JoinSet aborts every day still running
+        if let Some(task) = self.year_task.take() {
+            task.handle.abort();
+            self.status_message = Some(format!(
+                "Year run cancelled after {}",
+                format_duration(task.started.elapsed())
+            ));
+        }
+    }
+
+    /// Switches to the calendar of the current year with the cursor on the current day.
+    fn open_calendar(&mut self) {
+        self.current_screen = CurrentScreen::Calendar;
+        self.calendar_stale = true;
+        self.refresh_calendar();
+        self.calendar_cursor = self
+            .current_day
+            .parse()
+            .unwrap_or(1)
+            .clamp(1, self.calendar_days.len().max(1) as u32);
+    }
+
+    /// Rereads the days' status while the calendar is shown, when the year
+    /// changed or a day's files may have.
+    fn refresh_calendar(&mut self) {
+        if !matches!(self.current_screen, CurrentScreen::Calendar) {
+            return;
+        }
+        if !self.calendar_stale && self.calendar_year == self.current_year {
+            return;
+        }
+        let count = discovery::event_year(&self.current_year)
+            .map(calendar::days_in_year)
+            .unwrap_or(25);
+        self.calendar_days = (1..=count)
+            .map(|day| DayStatus::read(&self.current_year, &format!("{:02}", day)))
+            .collect();
+        self.calendar_year = self.current_year.clone();
+        self.calendar_stale = false;
+        self.calendar_cursor = self.calendar_cursor.clamp(1, count);
+    }
+
+    /// Moves the calendar's cursor by whole cells, staying on the grid.
+    fn move_calendar(&mut self, dx: i32, dy: i32) {
+        let columns = self.calendar_columns() as i32;
+        let count = self.calendar_days.len() as i32;
+        let target = self.calendar_cursor as i32 + dx + dy * columns;
+        if (1..=count).contains(&target) {
+            self.calendar_cursor = target as u32;
+        }
+    }
+
+    /// Opens the day under the calendar's cursor on the dashboard.
+    fn open_calendar_day(&mut self) {
+        let day = format!("{:02}", self.calendar_cursor);
+        if self.open_day(day) {
+            self.current_screen = CurrentScreen::Dashboard;
+        }
+    }
+
+    /// Switches to the year table and runs every day of the selected year,
+    /// unless a year run is already going.
+    fn start_year_run(&mut self) {
+        self.current_screen = CurrentScreen::Year;
+        if self.year_task.is_some() {
+            return;
+        }
+        let year = self.current_year.clone();
+        let days = year::runnable_days(&year);
+        if days.is_empty() {
+            self.error_message = Some(format!("No days with solutions found for {}", year));
+            return;
+        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        let config = self.config.clone();
+        let task_year = year.clone();
+        let handle = tokio::spawn(async move {
+            let mut on_day = |row: &DayRow| {
+                let _ = tx.send(YearMessage::Day(row.clone()));
+            };
+            let report =
+                year::run_year(&task_year, &config, year::default_jobs(), &mut on_day).await;
+            let _ = tx.send(YearMessage::Finished(report.wall_time.unwrap_or_default()));
+        });
+        self.year_report = Some(YearReport::new(&year));
+        self.year_task = Some(YearTask {
+            handle,
+            rx,
+            started: Instant::now(),
+            days,
+        });
+    }
+
+    fn poll_year(&mut self) {
+        let Some(task) = self.year_task.as_mut() else {
+            return;
+        };
+        let mut finished = false;
+        loop {
+            match task.rx.try_recv() {
+                Ok(YearMessage::Day(row)) => {
+                    if let Some(report) = self.year_report.as_mut() {
+                        report.insert(row);
+                    }
+                    self.calendar_stale = true;
+                }
+                Ok(YearMessage::Finished(wall_time)) => {
+                    if let Some(report) = self.year_report.as_mut() {
+                        report.wall_time = Some(wall_time);
+                    }
+                    finished = true;
+                }
+                Err(mpsc::error::TryRecvError::Empty) => break,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+        if finished {
+            self.year_task = None;
+            self.load_status();
+        }
+    }
 
-            // Execute with timing
-            let run_start = Instant::now();
-            let run_result = self.execute_binary(&bin_path, &input_content).await;
-            let run_time = run_start.elapsed();
+    fn poll_notices(&mut self) {
+        while let Ok(notice) = self.notice_rx.try_recv() {
+            // Downloads and submissions write the day's inputs and answers
+            self.load_status();
+            match notice {
+                Notice::Status(status) => self.status_message = Some(status),
+                Notice::Error(err) => self.error_message = Some(err),
+                Notice::PuzzleUpdated { year, day } => {
+                    if year == self.current_year && day == self.current_day {
+                        self.load_puzzle();
+                    }
+                }
+            }
+        }
+    }
 
-            match run_result {
+    /// Switches to the puzzle screen, downloading the description if it is not cached yet.
+    fn open_puzzle(&mut self) {
+        self.current_screen = CurrentScreen::Puzzle;
+        self.puzzle_scroll = 0;
+        if !self.load_puzzle() {
+            self.fetch_puzzle();
+        }
+    }
+
+    /// Renders the cached description; returns false when there is none.
+    fn load_puzzle(&mut self) -> bool {
+        match puzzle::load_cached(&self.current_year, &self.current_day) {
+            Some(html) => {
+                self.puzzle_articles = puzzle::parse(&html);
+                self.selected_block = None;
+                self.render_puzzle();
+                match examples::fill_from_puzzle(&self.current_year, &self.current_day) {
+                    Ok(written) if !written.is_empty() => {
+                        self.load_status();
+                        self.status_message = Some(format!("Filled {}", written.join(", ")));
+                    }
+                    Ok(_) => {}
+                    Err(e) => self.error_message = Some(format!("{:#}", e)),
+                }
+                true
+            }
+            None => {
+                self.puzzle_articles = Vec::new();
+                self.selected_block = None;
+                self.puzzle_lines = vec![Line::from(Span::styled(
+                    "No cached description. Press 'f' to download it (needs AOC_SESSION).",
+                    Style::default().fg(colors::MUTED_GRAY),
+                ))];
+                false
+            }
+        }
+    }
+
+    fn fetch_puzzle(&mut self) {
+        let client = match HttpClient::from_config(&self.config) {
+            Ok(Some(client)) => client,
+            Ok(None) => return,
+            Err(e) => {
+                self.error_message = Some(format!("{:#}", e));
+                return;
+            }
+        };
+        self.status_message = Some(String::from("Downloading puzzle description..."));
+        let year = self.current_year.clone();
+        let day = self.current_day.clone();
+        let tx = self.notice_tx.clone();
+        tokio::spawn(async move {
+            match puzzle::fetch_description(&client, &year, &day).await {
+                Ok(_) => {
+                    let _ = tx.send(Notice::Status(format!(
+                        "Downloaded {}/{} puzzle description",
+                        year, day
+                    )));
+                    let _ = tx.send(Notice::PuzzleUpdated { year, day });
+                }
                 Err(e) => {
-                    output_lines.push(Line::from(vec![
-                        Span::styled("  ✗ Error: ", Style::default().fg(colors::AURORA_RED)),
-                        Span::raw(e),
-                    ]));
+                    let _ = tx.send(Notice::Error(format!("{:#}", e)));
                 }
-                Ok((stdout, stderr)) => {
-                    // Parse PART1 and PART2 from output
-                    let mut part1 = None;
-                    let mut part2 = None;
-                    for line in stdout.lines() {
-                        if let Some(val) = line.strip_prefix("PART1:") {
-                            part1 = Some(val.to_string());
-                        } else if let Some(val) = line.strip_prefix("PART2:") {
-                            part2 = Some(val.to_string());
+            }
+        });
+    }
+
+    fn render_puzzle(&mut self) {
+        self.puzzle_lines = Self::puzzle_text_lines(&self.puzzle_articles, self.selected_block);
+    }
+
+    /// Moves the code block selection, starting from the first block.
+    fn select_block(&mut self, delta: isize) {
+        let count = examples::code_blocks(&self.puzzle_articles).len();
+        if count == 0 {
+            self.error_message = Some(String::from("The description has no code blocks"));
+            return;
+        }
+        let next = match self.selected_block {
+            Some(index) => (index as isize + delta).rem_euclid(count as isize) as usize,
+            None => 0,
+        };
+        self.selected_block = Some(next);
+        self.render_puzzle();
+    }
+
+    /// Writes the selected code block as its part's example input.
+    fn use_selected_block(&mut self) {
+        let blocks = examples::code_blocks(&self.puzzle_articles);
+        let Some(block) = self.selected_block.and_then(|index| blocks.get(index)) else {
+            self.error_message = Some(String::from("Pick a code block with '[' or ']' first"));
+            return;
+        };
+        match examples::use_block(&self.current_year, &self.current_day, block) {
+            Ok(path) => {
+                self.load_status();
+                self.status_message = Some(format!(
+                    "Wrote part {} example to {}",
+                    block.part,
+                    path.display()
+                ));
+            }
+            Err(e) => self.error_message = Some(format!("{:#}", e)),
+        }
+    }
+
+    fn puzzle_text_lines(articles: &[Article], selected: Option<usize>) -> Vec<Line<'static>> {
+        let span_style = |span: &TextSpan| match (span.code, span.emphasis) {
+            (true, true) => Style::default()
+                .fg(colors::AURORA_YELLOW)
+                .add_modifier(Modifier::BOLD),
+            (true, false) => Style::default().fg(colors::AURORA_GREEN),
+            (false, true) => Style::default()
+                .fg(colors::SNOW_WHITE)
+                .add_modifier(Modifier::BOLD),
+            (false, false) => Style::default().fg(colors::SNOW_WHITE),
+        };
+
+        let mut lines = Vec::new();
+        let mut code_index = 0;
+        for article in articles {
+            for block in &article.blocks {
+                match block.kind {
+                    BlockKind::Heading => lines.push(Line::from(Span::styled(
+                        block.text(),
+                        Style::default()
+                            .fg(colors::FROST_CYAN)
+                            .add_modifier(Modifier::BOLD),
+                    ))),
+                    BlockKind::Paragraph | BlockKind::ListItem => {
+                        let mut spans = Vec::new();
+                        if block.kind == BlockKind::ListItem {
+                            spans.push(Span::styled(
+                                "  • ",
+                                Style::default().fg(colors::FROST_CYAN),
+                            ));
+                        }
+                        for span in &block.spans {
+                            spans.push(Span::styled(span.text.clone(), span_style(span)));
                         }
+                        lines.push(Line::from(spans));
                     }
-
-                    // Format Part 1
-                    if let Some(p1) = &part1 {
-                        let (status_icon, status_color) = if input_name == "test" {
-                            match &solution_1 {
-                                Some(exp) if exp == p1 => ("✓", colors::AURORA_GREEN),
-                                Some(_) => ("✗", colors::AURORA_RED),
-                                None => ("?", colors::AURORA_YELLOW),
-                            }
+                    BlockKind::Code => {
+                        // The picked block gets a marker in its gutter
+                        let gutter = if selected == Some(code_index) {
+                            Span::styled("  ▌ ", Style::default().fg(colors::AURORA_YELLOW))
                         } else {
-                            ("→", colors::FROST_CYAN)
+                            Span::raw("    ")
                         };
-
-                        let mut spans = vec![
-                            Span::styled(
-                                format!("  {} ", status_icon),
-                                Style::default().fg(status_color),
-                            ),
-                            Span::styled("Part 1: ", Style::default().fg(colors::MUTED_GRAY)),
-                            Span::styled(
-                                p1.clone(),
+                        code_index += 1;
+                        // Split spans on newlines so the block keeps its layout
+                        let mut line = vec![gutter.clone()];
+                        for span in &block.spans {
+                            let style = if span.emphasis {
                                 Style::default()
-                                    .fg(colors::SNOW_WHITE)
-                                    .add_modifier(Modifier::BOLD),
-                            ),
-                        ];
-
-                        if input_name == "test" {
-                            if let Some(exp) = &solution_1 {
-                                if exp != p1 {
-                                    spans.push(Span::styled(
-                                        format!(" (expected: {})", exp),
-                                        Style::default().fg(colors::AURORA_RED),
-                                    ));
+                                    .fg(colors::AURORA_YELLOW)
+                                    .add_modifier(Modifier::BOLD)
+                            } else {
+                                Style::default().fg(colors::AURORA_GREEN)
+                            };
+                            for (i, part) in span.text.split('\n').enumerate() {
+                                if i > 0 {
+                                    lines.push(Line::from(std::mem::replace(
+                                        &mut line,
+                                        vec![gutter.clone()],
+                                    )));
                                 }
+                                line.push(Span::styled(part.to_string(), style));
                             }
                         }
-
-                        output_lines.push(Line::from(spans));
+                        lines.push(Line::from(line));
                     }
+                }
+                // ListItems run together like the site's bullet lists
+                if block.kind != BlockKind::ListItem {
+                    lines.push(Line::default());
+                }
+            }
+        }
+        lines
+    }
 
-                    // Format Part 2
-                    if let Some(p2) = &part2 {
-                        let (status_icon, status_color) = if input_name == "test" {
-                            match &solution_2 {
-                                Some(exp) if exp == p2 => ("✓", colors::AURORA_GREEN),
-                                Some(_) => ("✗", colors::AURORA_RED),
-                                None => ("?", colors::AURORA_YELLOW),
-                            }
-                        } else {
-                            ("→", colors::FROST_CYAN)
-                        };
+    fn scroll_puzzle(&mut self, delta: i32) {
+        // The scroll offset counts wrapped rows, so the limit depends on the
+        // pane's current size; the last page stays full
+        let (width, height) = crossterm::terminal::size().unwrap_or_default();
+        let [_, pane, _, _] = Self::puzzle_screen_areas(Rect::new(0, 0, width, height));
+        let rows = Paragraph::new(self.puzzle_lines.clone())
+            .wrap(Wrap { trim: false })
+            .line_count(pane.width.saturating_sub(2));
+        let max = rows.saturating_sub(pane.height.saturating_sub(2) as usize) as i32;
+        self.puzzle_scroll = (self.puzzle_scroll as i32 + delta).clamp(0, max) as u16;
+    }
+
+    /// Downloads the selected day's input in the background when a session
+    /// token is configured and `input.txt` is still empty.
+    fn start_download(&mut self) {
+        let client = match HttpClient::from_config(&self.config) {
+            Ok(Some(client)) => client,
+            Ok(None) => return,
+            Err(e) => {
+                self.error_message = Some(format!("{:#}", e));
+                return;
+            }
+        };
+        let year = self.current_year.clone();
+        let day = self.current_day.clone();
+        let tx = self.notice_tx.clone();
+        tokio::spawn(async move {
+            let notice = match download::download_input(&client, &year, &day, Utc::now()).await {
+                Ok(DownloadOutcome::AlreadyPresent) => return,
+                Ok(DownloadOutcome::Downloaded { bytes }) => Notice::Status(format!(
+                    "Downloaded {}/{} input ({} bytes)",
+                    year, day, bytes
+                )),
+                Ok(DownloadOutcome::Locked { unlocks_at }) => Notice::Status(format!(
+                    "{}/{} unlocks at {}",
+                    year,
+                    day,
+                    unlocks_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+                )),
+                Err(e) => Notice::Error(format!("{:#}", e)),
+            };
+            let _ = tx.send(notice);
+        });
+    }
+
+    /// Drains progress messages sent by the background run task.
+    fn poll_run(&mut self) {
+        let Some(task) = self.running.as_mut() else {
+            return;
+        };
+        let mut finished = false;
+        loop {
+            match task.rx.try_recv() {
+                Ok(RunMessage::Phase(phase)) => task.phase = phase,
+                Ok(RunMessage::Output(lines)) => self.run_output.extend(lines),
+                Ok(RunMessage::Finished(report)) => {
+                    self.last_report = Some(*report);
+                    finished = true;
+                }
+                Err(mpsc::error::TryRecvError::Empty) => break,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+        if finished {
+            let benchmark = task.benchmark;
+            self.running = None;
+            if benchmark {
+                self.load_bench_history();
+            } else {
+                self.load_status();
+            }
+        }
+    }
+
+    async fn run_solution(
+        year: String,
+        day: String,
+        options: RunOptions,
+        tx: mpsc::UnboundedSender<RunMessage>,
+    ) {
+        let mut on_event = |event: RunEvent<'_>| {
+            let message = match event {
+                RunEvent::Phase(phase) => RunMessage::Phase(phase),
+                RunEvent::Compiled(report) => RunMessage::Output(Self::compile_lines(report)),
+                RunEvent::Input(result) => RunMessage::Output(Self::input_lines(result)),
+            };
+            let _ = tx.send(message);
+        };
+        let report = runner::run_day(&year, &day, options, &mut on_event).await;
+
+        if report.compile.is_success() && report.inputs.is_empty() {
+            let _ = tx.send(RunMessage::Output(vec![
+                Line::from(Span::styled(
+                    "No inputs provided.",
+                    Style::default().fg(colors::AURORA_YELLOW),
+                )),
+                Line::from(Span::styled(
+                    "Add content to test.txt, tests/<case>/input.txt or input.txt",
+                    Style::default().fg(colors::MUTED_GRAY),
+                )),
+            ]));
+        }
+        let _ = tx.send(RunMessage::Finished(Box::new(report)));
+    }
 
-                        let mut spans = vec![
+    fn compile_lines(report: &RunReport) -> Vec<Line<'static>> {
+        let outcome = &report.compile;
+        match outcome {
+            CompileOutcome::Unavailable(e) => vec![Line::from(vec![
+                Span::styled("✗ ", Style::default().fg(colors::AURORA_RED)),
+                Span::styled(e.clone(), Style::default().fg(colors::AURORA_RED)),
+            ])],
+            CompileOutcome::Failed(stderr) => {
+                let mut lines = vec![
+                    Line::from(vec![
+                        Span::styled("✗ ", Style::default().fg(colors::AURORA_RED)),
+                        Span::styled(
+                            "Compilation Failed",
+                            Style::default()
+                                .fg(colors::AURORA_RED)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ]),
+                    Line::from(""),
+                ];
+                for line in stderr.lines() {
+                    lines.push(Line::from(Span::styled(
+                        line.to_string(),
+                        Style::default().fg(colors::AURORA_RED),
+                    )));
+                }
+                lines
+            }
+            CompileOutcome::Compiled(_) | CompileOutcome::Cached | CompileOutcome::Interpreted => {
+                let (label, detail) = match outcome {
+                    CompileOutcome::Compiled(compile_time) => (
+                        "Compiled",
+                        format!(
+                            "{}, {}",
+                            format_duration(*compile_time),
+                            report.profile.name()
+                        ),
+                    ),
+                    CompileOutcome::Cached => {
+                        ("Compiled", format!("cached, {}", report.profile.name()))
+                    }
+                    _ => ("Ready", String::from("interpreted")),
+                };
+                vec![
+                    Line::from(vec![
+                        Span::styled("✓ ", Style::default().fg(colors::AURORA_GREEN)),
+                        Span::styled(label, Style::default().fg(colors::AURORA_GREEN)),
+                        Span::styled(
+                            format!(" ({}, {})", detail, report.backend.name()),
+                            Style::default().fg(colors::MUTED_GRAY),
+                        ),
+                    ]),
+                    Line::from(""),
+                ]
+            }
+        }
+    }
+
+    fn input_lines(result: &InputResult) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+
+        // Section header
+        let header_style = Style::default()
+            .fg(colors::FROST_CYAN)
+            .add_modifier(Modifier::BOLD);
+        lines.push(Line::from(Span::styled(
+            format!("─── {} ───", result.title()),
+            header_style,
+        )));
+
+        match &result.outcome {
+            Err(ExecError::Timeout(limit)) => {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        "  ✗ TIMEOUT",
+                        Style::default()
+                            .fg(colors::AURORA_RED)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(" (killed after {})", format_duration(*limit)),
+                        Style::default().fg(colors::MUTED_GRAY),
+                    ),
+                ]));
+            }
+            Err(ExecError::OutOfMemory(limit_mb)) => {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        "  ✗ OOM",
+                        Style::default()
+                            .fg(colors::AURORA_RED)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(" (exceeded {}MB)", limit_mb),
+                        Style::default().fg(colors::MUTED_GRAY),
+                    ),
+                ]));
+            }
+            Err(ExecError::Failed(e)) => {
+                lines.push(Line::from(vec![
+                    Span::styled("  ✗ Error: ", Style::default().fg(colors::AURORA_RED)),
+                    Span::raw(e.clone()),
+                ]));
+            }
+            Ok(output) => {
+                for (index, part) in [&output.part1, &output.part2].into_iter().enumerate() {
+                    let label = format!("Part {}", index + 1);
+                    match (part, &result.expected[index]) {
+                        (Some(part), _) => {
+                            lines.push(Self::part_line(&label, part, result.is_test))
+                        }
+                        // A part with a known answer that printed nothing fails
+                        (None, Some(exp)) => lines.push(Line::from(vec![
+                            Span::styled("  ✗ ", Style::default().fg(colors::AURORA_RED)),
                             Span::styled(
-                                format!("  {} ", status_icon),
-                                Style::default().fg(status_color),
+                                format!("{}: no answer", label),
+                                Style::default().fg(colors::MUTED_GRAY),
                             ),
-                            Span::styled("Part 2: ", Style::default().fg(colors::MUTED_GRAY)),
                             Span::styled(
-                                p2.clone(),
-                                Style::default()
-                                    .fg(colors::SNOW_WHITE)
-                                    .add_modifier(Modifier::BOLD),
+                                format!(" (expected: {})", exp),
+                                Style::default().fg(colors::AURORA_RED),
                             ),
-                        ];
-
-                        if input_name == "test" {
-                            if let Some(exp) = &solution_2 {
-                                if exp != p2 {
-                                    spans.push(Span::styled(
-                                        format!(" (expected: {})", exp),
-                                        Style::default().fg(colors::AURORA_RED),
-                                    ));
-                                }
-                            }
-                        }
-
-                        output_lines.push(Line::from(spans));
+                        ])),
+                        (None, None) => {}
                     }
+                }
 
-                    // Timing line
-                    output_lines.push(Line::from(Span::styled(
-                        format!("  ⏱  {}", Self::format_duration(run_time)),
+                for warning in &output.warnings {
+                    lines.push(Line::from(vec![
+                        Span::styled("  ⚠ ", Style::default().fg(colors::AURORA_YELLOW)),
+                        Span::styled(warning.clone(), Style::default().fg(colors::AURORA_YELLOW)),
+                    ]));
+                }
+                for log in output.logs.iter().take(MAX_OUTPUT_LINES) {
+                    lines.push(Line::from(vec![
+                        Span::styled("  ▸ ", Style::default().fg(colors::FROST_CYAN)),
+                        Span::styled(log.clone(), Style::default().fg(colors::MUTED_GRAY)),
+                    ]));
+                }
+                for line in output.output.iter().take(MAX_OUTPUT_LINES) {
+                    lines.push(Line::from(vec![
+                        Span::styled("  │ ", Style::default().fg(colors::MUTED_GRAY)),
+                        Span::raw(line.clone()),
+                    ]));
+                }
+                let hidden = output.logs.len().saturating_sub(MAX_OUTPUT_LINES)
+                    + output.output.len().saturating_sub(MAX_OUTPUT_LINES);
+                if hidden > 0 {
+                    lines.push(Line::from(Span::styled(
+                        format!("  … {} more lines", hidden),
                         Style::default().fg(colors::MUTED_GRAY),
                     )));
+                }
 
-                    // Show stderr, but hide todo!() panics entirely
-                    let is_todo_panic = stderr.contains("not yet implemented");
-                    if !is_todo_panic && !stderr.trim().is_empty() {
-                        output_lines.push(Line::from(vec![
-                            Span::styled("  stderr: ", Style::default().fg(colors::AURORA_YELLOW)),
-                            Span::raw(stderr.trim().to_string()),
-                        ]));
-                    }
+                // Timing line, with the stages the solution measured itself
+                lines.push(Line::from(Span::styled(
+                    format!("  ⏱  {}", format_duration(result.run_time)),
+                    Style::default().fg(colors::MUTED_GRAY),
+                )));
+                for (stage, time) in result.stage_breakdown() {
+                    let bar_color = if stage == "overhead" {
+                        colors::MUTED_GRAY
+                    } else {
+                        colors::FROST_CYAN
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("     {:<8} {:>8}  ", stage, format_duration(time)),
+                            Style::default().fg(colors::MUTED_GRAY),
+                        ),
+                        Span::styled(
+                            duration_bar(time, result.run_time, STAGE_BAR_WIDTH),
+                            Style::default().fg(bar_color),
+                        ),
+                    ]));
+                }
+
+                // Show stderr, but hide todo!() panics entirely
+                let is_todo_panic = output.stderr.contains("not yet implemented");
+                if !is_todo_panic && !output.stderr.trim().is_empty() {
+                    lines.push(Line::from(vec![
+                        Span::styled("  stderr: ", Style::default().fg(colors::AURORA_YELLOW)),
+                        Span::raw(output.stderr.trim().to_string()),
+                    ]));
                 }
             }
-            output_lines.push(Line::from(""));
         }
+        lines.push(Line::from(""));
+        lines
+    }
 
-        if !has_any_input {
-            output_lines.push(Line::from(Span::styled(
-                "No inputs provided.",
-                Style::default().fg(colors::AURORA_YELLOW),
-            )));
-            output_lines.push(Line::from(Span::styled(
-                "Add content to test.txt or input.txt",
+    fn part_line(label: &str, part: &PartResult, is_test: bool) -> Line<'static> {
+        // Real input without a confirmed answer is neither right nor wrong yet
+        let (status_icon, status_color) = match part.verdict() {
+            Verdict::Pass => ("✓", colors::AURORA_GREEN),
+            Verdict::Fail => ("✗", colors::AURORA_RED),
+            Verdict::Unknown if is_test => ("?", colors::AURORA_YELLOW),
+            Verdict::Unknown => ("→", colors::FROST_CYAN),
+        };
+
+        let mut spans = vec![
+            Span::styled(
+                format!("  {} ", status_icon),
+                Style::default().fg(status_color),
+            ),
+            Span::styled(
+                format!("{}: ", label),
                 Style::default().fg(colors::MUTED_GRAY),
-            )));
-        }
+            ),
+            Span::styled(
+                part.answer.clone(),
+                Style::default()
+                    .fg(colors::SNOW_WHITE)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ];
 
-        self.run_output = output_lines;
-        Ok(())
-    }
+        if part.verdict() == Verdict::Fail
+            && let Some(exp) = &part.expected
+        {
+            spans.push(Span::styled(
+                format!(" (expected: {})", exp),
+                Style::default().fg(colors::AURORA_RED),
+            ));
+        }
 
-    fn format_duration(d: Duration) -> String {
-        if d.as_secs() >= 1 {
-            format!("{:.2}s", d.as_secs_f64())
-        } else if d.as_millis() >= 1 {
-            format!("{}ms", d.as_millis())
-        } else {
-            format!("{}µs", d.as_micros())
+        if let Some(warning) = &part.warning {
+            spans.push(Span::styled(
+                format!("  ⚠ {}", warning),
+                Style::default().fg(colors::AURORA_YELLOW),
+            ));
         }
+
+        Line::from(spans)
     }
 
-    async fn execute_binary(
-        &self,
-        bin_path: &str,
-        input: &str,
-    ) -> std::result::Result<(String, String), String> {
-        let mut child = Command::new(bin_path)
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .map_err(|e| format!("Failed to start: {}", e))?;
-
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin
-                .write_all(input.as_bytes())
-                .await
-                .map_err(|e| format!("Failed to write input: {}", e))?;
-        }
-
-        let output = child
-            .wait_with_output()
-            .await
-            .map_err(|e| format!("Runtime error: {}", e))?;
-
-        Ok((
-            String::from_utf8_lossy(&output.stdout).to_string(),
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ))
+    /// The real input's output from the last run, if it was for the selected day.
+    fn last_real_output(&self) -> Option<&InputOutput> {
+        self.last_report
+            .as_ref()
+            .filter(|report| report.year == self.current_year && report.day == self.current_day)
+            .and_then(|report| report.real_input())
+            .and_then(|input| input.outcome.as_ref().ok())
     }
 
-    fn generate_missing_structure(&mut self) {
-        let base = format!("{}/{}", self.current_year, self.current_day);
-        if Path::new(&base).exists() {
+    /// Stores the last run's real-input answers as confirmed in `answers.json`.
+    fn accept_answers(&mut self) {
+        let Some(output) = self.last_real_output() else {
+            self.error_message = Some(String::from("Run the real input before accepting answers"));
             return;
-        }
+        };
 
-        if self
-            .try_io(
-                fs::create_dir_all(&base),
-                &format!("Failed to create {}", base),
-            )
-            .is_none()
-        {
+        let mut answers = match Answers::load(&self.current_year, &self.current_day) {
+            Ok(answers) => answers,
+            Err(e) => {
+                self.error_message = Some(format!("{:#}", e));
+                return;
+            }
+        };
+        let mut accepted = Vec::new();
+        for (part, result) in [(1, &output.part1), (2, &output.part2)] {
+            // The template prints "--" for parts that are still todo!()
+            if let Some(result) = result.as_ref().filter(|r| r.answer != "--") {
+                let _ = answers.set(part, result.answer.clone());
+                accepted.push(format!("part {} = {}", part, result.answer));
+            }
+        }
+        if accepted.is_empty() {
+            self.error_message = Some(String::from("No real-input answers to accept"));
             return;
         }
 
-        let files = ["test.txt", "input.txt", "solution_1.txt", "solution_2.txt"];
-        for file in files {
-            let path = format!("{}/{}", base, file);
-            if self
-                .try_io(File::create(&path), &format!("Failed to create {}", path))
-                .is_none()
-            {
-                return;
+        match answers.save(&self.current_year, &self.current_day) {
+            Ok(()) => {
+                self.load_status();
+                self.status_message = Some(format!("Accepted {}", accepted.join(", ")));
             }
+            Err(e) => self.error_message = Some(format!("{:#}", e)),
         }
+    }
 
-        let template = format!(
-            r#"// Advent of Code {year} - Day {day}
-use std::io::{{self, Read}};
-use std::panic::catch_unwind;
-
-fn solve_1(input: &str) -> String {{
-    todo!("Part 1")
-}}
-
-fn solve_2(input: &str) -> String {{
-    todo!("Part 2")
-}}
-
-fn main() {{
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    let input = input.trim().to_string();
-
-    match catch_unwind(|| solve_1(&input)) {{
-        Ok(result) => println!("PART1:{{}}", result),
-        Err(_) => println!("PART1:--"),
-    }}
-    match catch_unwind(|| solve_2(&input)) {{
-        Ok(result) => println!("PART2:{{}}", result),
-        Err(_) => println!("PART2:--"),
-    }}
-}}
-"#,
-            year = self.current_year,
-            day = self.current_day
-        );
-
-        if self
-            .try_io(
-                fs::write(format!("{}/run.rs", base), template),
-                "Failed to write run.rs",
-            )
-            .is_none()
-        {
+    /// Asks to confirm submitting the last run's real-input answer for `part`.
+    fn request_submit(&mut self, part: u8) {
+        let answer = self.last_real_output().and_then(|output| match part {
+            1 => output.part1.as_ref(),
+            _ => output.part2.as_ref(),
+        });
+        let Some(answer) = answer.map(|result| result.answer.clone()) else {
+            self.error_message = Some(format!(
+                "Run the real input before submitting part {}",
+                part
+            ));
             return;
-        }
+        };
+        self.pending_submit = Some((part, answer));
+    }
 
-        let cargo_toml = format!(
-            r#"[package]
-name = "aoc-{year}-{day}"
-version = "0.1.0"
-edition = "2021"
+    /// Submits `answer` for `part` in the background.
+    fn submit_answer(&mut self, part: u8, answer: String) {
+        let client = match HttpClient::from_config(&self.config) {
+            Ok(Some(client)) => client,
+            Ok(None) => {
+                self.error_message = Some(String::from(client::MISSING_SESSION));
+                return;
+            }
+            Err(e) => {
+                self.error_message = Some(format!("{:#}", e));
+                return;
+            }
+        };
 
-[[bin]]
-name = "run"
-path = "run.rs"
-"#,
-            year = self.current_year,
-            day = self.current_day
-        );
+        self.status_message = Some(format!("Submitting part {}: {}...", part, answer));
+        let year = self.current_year.clone();
+        let day = self.current_day.clone();
+        let tx = self.notice_tx.clone();
+        tokio::spawn(async move {
+            let notice = match submit::submit_answer(&client, &year, &day, part, &answer).await {
+                Ok(SubmitOutcome::Correct) => {
+                    // Solving a part changes the page: part 2 unlocks, the answer is shown
+                    if puzzle::fetch_description(&client, &year, &day)
+                        .await
+                        .is_ok()
+                    {
+                        let _ = tx.send(Notice::PuzzleUpdated {
+                            year: year.clone(),
+                            day: day.clone(),
+                        });
+                    }
+                    Notice::Status(format!(
+                        "Part {} {}: {}",
+                        part,
+                        answer,
+                        SubmitOutcome::Correct
+                    ))
+                }
+                Ok(SubmitOutcome::AlreadySolved) => Notice::Status(format!(
+                    "Part {} {}: {}",
+                    part,
+                    answer,
+                    SubmitOutcome::AlreadySolved
+                )),
+                Ok(outcome) => Notice::Error(format!("Part {} {}: {}", part, answer, outcome)),
+                Err(e) => Notice::Error(format!("{:#}", e)),
+            };
+            let _ = tx.send(notice);
+        });
+    }
 
-        self.try_io(
-            fs::write(format!("{}/Cargo.toml", base), cargo_toml),
-            "Failed to write Cargo.toml",
-        );
+    fn generate_missing_structure(&mut self) {
+        if let Err(e) = scaffold::generate_missing_structure(&self.current_year, &self.current_day)
+        {
+            self.error_message = Some(format!("{:#}", e));
+        }
     }
 
-    async fn handle_events(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                // Clear error on any key press
-                self.error_message = None;
+    async fn handle_events(&mut self) -> Result<()> {
+        if event::poll(Duration::from_millis(100))?
+            && let Event::Key(key) = event::read()?
+        {
+            // Clear messages on any key press
+            self.error_message = None;
+            self.status_message = None;
+
+            // A pending submission takes the key as its answer
+            if let Some((part, answer)) = self.pending_submit.take() {
+                if key.code == KeyCode::Char('y') {
+                    self.submit_answer(part, answer);
+                } else {
+                    self.status_message = Some(String::from("Submission cancelled"));
+                }
+                return Ok(());
+            }
 
-                match key.code {
-                    KeyCode::Char('q') => self.exit = true,
-                    // CTRL + C
-                    KeyCode::Char('c') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
-                        self.exit = true;
-                    }
-                    // ESC
-                    KeyCode::Esc => {
-                        self.exit = true;
-                    }
-                    // Up Arrow
-                    KeyCode::Up => {
-                        self.nav_up();
-                    }
-                    KeyCode::Char('k') => {
-                        self.nav_up();
-                    }
-                    // Down Arrow
-                    KeyCode::Down => {
-                        self.nav_down();
-                    }
-                    KeyCode::Char('j') => {
-                        self.nav_down();
-                    }
-                    // Enter
-                    KeyCode::Enter => {
-                        self.nav_enter();
-                    }
+            let reading = matches!(self.current_screen, CurrentScreen::Puzzle) && !self.show_modal;
+            let in_year = matches!(self.current_screen, CurrentScreen::Year) && !self.show_modal;
+            let in_calendar =
+                matches!(self.current_screen, CurrentScreen::Calendar) && !self.show_modal;
+            match key.code {
+                KeyCode::Char('q') => self.exit = true,
+                // CTRL + C
+                KeyCode::Char('c') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+                    self.exit = true;
+                }
+                // ESC
+                KeyCode::Esc if reading => {
+                    self.current_screen = CurrentScreen::Dashboard;
+                }
+                KeyCode::Esc | KeyCode::Char('g') if in_calendar => {
+                    self.current_screen = CurrentScreen::Dashboard;
+                }
+                KeyCode::Left | KeyCode::Char('h') if in_calendar => self.move_calendar(-1, 0),
+                KeyCode::Right | KeyCode::Char('l') if in_calendar => self.move_calendar(1, 0),
+                KeyCode::Up | KeyCode::Char('k') if in_calendar => self.move_calendar(0, -1),
+                KeyCode::Down | KeyCode::Char('j') if in_calendar => self.move_calendar(0, 1),
+                KeyCode::Enter if in_calendar => self.open_calendar_day(),
+                KeyCode::Esc | KeyCode::Char('y') if in_year => {
+                    self.current_screen = CurrentScreen::Dashboard;
+                }
+                // r - rerun the year
+                KeyCode::Char('r') if in_year => {
+                    self.start_year_run();
+                }
+                KeyCode::Esc => {
+                    self.exit = true;
+                }
+                // Puzzle scrolling
+                KeyCode::Up | KeyCode::Char('k') if reading => self.scroll_puzzle(-1),
+                KeyCode::Down | KeyCode::Char('j') if reading => self.scroll_puzzle(1),
+                KeyCode::PageUp if reading => self.scroll_puzzle(-10),
+                KeyCode::PageDown | KeyCode::Char(' ') if reading => self.scroll_puzzle(10),
+                KeyCode::Char('f') if reading => self.fetch_puzzle(),
+                KeyCode::Char('[') if reading => self.select_block(-1),
+                KeyCode::Char(']') if reading => self.select_block(1),
+                KeyCode::Char('t') if reading => self.use_selected_block(),
+                // Up Arrow
+                KeyCode::Up => {
+                    self.nav_up();
+                }
+                KeyCode::Char('k') => {
+                    self.nav_up();
+                }
+                // Down Arrow
+                KeyCode::Down => {
+                    self.nav_down();
+                }
+                KeyCode::Char('j') => {
+                    self.nav_down();
+                }
+                // Enter
+                KeyCode::Enter => {
+                    self.nav_enter();
+                }
 
-                    // c - config
-                    KeyCode::Char('c') => {
-                        self.selection_level = SelectionLevel::Year;
-                        self.show_modal = !self.show_modal;
+                // c - config
+                KeyCode::Char('c') => {
+                    self.selection_level = SelectionLevel::Year;
+                    self.show_modal = !self.show_modal;
+                    if self.show_modal {
+                        self.refresh_years();
                     }
+                }
 
-                    // r - run
-                    KeyCode::Char('r') => {
-                        self.run_output = vec![Line::from(Span::styled(
-                            "⟳ Compiling...",
-                            Style::default().fg(colors::AURORA_YELLOW),
-                        ))];
-                        terminal.draw(|frame| self.draw(frame))?;
-                        if let Err(e) = self.run_solution().await {
-                            self.error_message = Some(e.to_string());
-                        }
-                    }
+                // r - run
+                KeyCode::Char('r') => {
+                    self.start_run();
+                }
+
+                // g - calendar grid of the year
+                KeyCode::Char('g') => {
+                    self.open_calendar();
+                }
+
+                // y - run the whole year
+                KeyCode::Char('y') => {
+                    self.start_year_run();
+                }
+
+                // b - benchmark
+                KeyCode::Char('b') => {
+                    self.start_benchmark();
+                }
 
-                    // e - mock error (for testing)
-                    KeyCode::Char('e') => {
-                        self.error_message =
-                            Some("This is a mock error to test the UI!".to_string());
+                // x - cancel run
+                KeyCode::Char('x') => {
+                    self.cancel_run();
+                }
+
+                // a - accept real-input answers
+                KeyCode::Char('a') => {
+                    self.accept_answers();
+                }
+
+                // 1 / 2 - submit real-input answer, after confirming with y
+                KeyCode::Char('1') => {
+                    self.request_submit(1);
+                }
+                KeyCode::Char('2') => {
+                    self.request_submit(2);
+                }
+
+                // v - toggle puzzle view
+                KeyCode::Char('v') => match self.current_screen {
+                    CurrentScreen::Dashboard | CurrentScreen::Year | CurrentScreen::Calendar => {
+                        self.open_puzzle()
                     }
-                    _ => {}
+                    CurrentScreen::Puzzle => self.current_screen = CurrentScreen::Dashboard,
+                },
+
+                // p - cycle build profile
+                KeyCode::Char('p') => {
+                    self.config.profile = self.config.profile.next();
+                }
+
+                // e - mock error (for testing)
+                KeyCode::Char('e') => {
+                    self.error_message = Some("This is a mock error to test the UI!".to_string());
                 }
+                _ => {}
             }
         }
         Ok(())
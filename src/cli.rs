@@ -0,0 +1,673 @@
+use crate::core::answers::{Answers, Hint};
+use crate::core::bench::{self, BenchEvent, BenchOptions, Change};
+use crate::core::client::{self, HttpClient};
+use crate::core::config::{BuildMode, BuildProfile, Config, RunOptions};
+use crate::core::discovery;
+use crate::core::download::{self, DownloadOutcome};
+use crate::core::examples;
+use crate::core::puzzle;
+use crate::core::report::RunRecord;
+use crate::core::runner::{
+    self, CompileOutcome, ExecError, InputResult, PartResult, RunEvent, RunReport, Verdict,
+};
+use crate::core::status::DayStatus;
+use crate::core::submit::{self, SubmitOutcome};
+use crate::core::{duration_bar, format_duration, normalize_day, scaffold, year};
+use chrono::{Local, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use color_eyre::eyre::{Result, eyre};
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Width of the per-stage timing bars at 100% of an input's run time
+const STAGE_BAR_WIDTH: usize = 20;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code workflow manager", version)]
+pub struct Cli {
+    /// Runs headless instead of launching the dashboard
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Build a day's solution and run it against its test cases and real input
+    ///
+    /// The backend follows the day's files: run.rs (rustc or cargo), main.go,
+    /// main.exs, a 2023 Runner module or a src/day_N cargo bin. Exits non-zero
+    /// when a test answer mismatches or a run fails.
+    Run {
+        year: String,
+        day: String,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Overrides the build profile saved in config.json
+        #[arg(long, value_enum)]
+        profile: Option<BuildProfile>,
+        /// Overrides the build mode saved in config.json, e.g. `elixir` for
+        /// a day that also has a main.go
+        #[arg(long, value_enum, alias = "backend")]
+        build_mode: Option<BuildMode>,
+    },
+    /// Run a day's optimized build repeatedly and report timing statistics
+    ///
+    /// Debug profiles are benchmarked as release. The result is saved to the
+    /// day's bench.json, which the dashboard shows.
+    Bench {
+        year: String,
+        day: String,
+        /// Measured runs per input (defaults to config.json, then 20)
+        #[arg(long)]
+        runs: Option<u32>,
+        /// Unmeasured runs before them (defaults to config.json, then 3)
+        #[arg(long)]
+        warmup: Option<u32>,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Overrides the build profile saved in config.json
+        #[arg(long, value_enum)]
+        profile: Option<BuildProfile>,
+        /// Overrides the build mode saved in config.json
+        #[arg(long, value_enum, alias = "backend")]
+        build_mode: Option<BuildMode>,
+    },
+    /// Show every recorded benchmark of a day and flag changes between them
+    ///
+    /// Each benchmark is compared with the one before it for the same input
+    /// and profile.
+    History {
+        year: String,
+        day: String,
+        /// Only show benchmarks of this profile
+        #[arg(long, value_enum)]
+        profile: Option<BuildProfile>,
+    },
+    /// Build and run every day of a year in parallel and print a summary table
+    ///
+    /// Each part shows its test status (✓ ✗ ? or · for none) and the real
+    /// answer, starred when it matches the confirmed one. Exits non-zero when
+    /// a test or answer is wrong or a day fails to build or run.
+    Year {
+        year: String,
+        /// Days built and run at once (defaults to the number of CPUs)
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
+    /// Scaffold a day's directory, inputs and solution template
+    New { year: String, day: String },
+    /// Download a day's puzzle input into input.txt (needs AOC_SESSION)
+    Fetch { year: String, day: String },
+    /// Fill test.txt and solution files from the puzzle's example, or list its code blocks
+    Example {
+        year: String,
+        day: String,
+        /// Use this code block (numbered as listed) as the example instead
+        #[arg(long)]
+        block: Option<usize>,
+    },
+    /// Record a confirmed real-input answer in the day's answers.json
+    Answer {
+        year: String,
+        day: String,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+    },
+    /// Record a rejected real-input answer, optionally with AoC's hint
+    Reject {
+        year: String,
+        day: String,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+        #[arg(long, value_enum)]
+        hint: Option<Hint>,
+    },
+    /// Submit a real-input answer to AoC and record the verdict (needs AOC_SESSION)
+    Submit {
+        year: String,
+        day: String,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+    },
+    /// List the years and days found on disk and the layout each uses
+    List { year: Option<String> },
+    /// Show which files are ready for a day (defaults to the dashboard's day)
+    Status {
+        year: Option<String>,
+        day: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    /// One JSON record per input, one per line
+    Json,
+}
+
+pub async fn execute(command: Command) -> Result<ExitCode> {
+    match command {
+        Command::Run {
+            year,
+            day,
+            format,
+            profile,
+            build_mode,
+        } => {
+            let mut options = load_config()?.run_options(&year, &normalize_day(&day));
+            options.profile = profile.unwrap_or(options.profile);
+            options.build_mode = build_mode.unwrap_or(options.build_mode);
+            run(&year, &normalize_day(&day), format, options).await
+        }
+        Command::Bench {
+            year,
+            day,
+            runs,
+            warmup,
+            format,
+            profile,
+            build_mode,
+        } => {
+            let config = load_config()?;
+            let mut options = config.run_options(&year, &normalize_day(&day));
+            options.profile = profile.unwrap_or(options.profile);
+            options.build_mode = build_mode.unwrap_or(options.build_mode);
+            let bench = BenchOptions {
+                runs: runs.unwrap_or(config.bench.runs),
+                warmup: warmup.unwrap_or(config.bench.warmup),
+            };
+            benchmark(&year, &normalize_day(&day), bench, options, format).await
+        }
+        Command::History { year, day, profile } => {
+            bench_history(&year, &normalize_day(&day), profile)
+        }
+        Command::Year { year, jobs } => {
+            run_year(&year, jobs.unwrap_or_else(year::default_jobs)).await
+        }
+        Command::New { year, day } => new(&year, &normalize_day(&day)),
+        Command::Fetch { year, day } => fetch(&year, &normalize_day(&day)).await,
+        Command::Example { year, day, block } => example(&year, &normalize_day(&day), block).await,
+        Command::Answer {
+            year,
+            day,
+            part,
+            answer,
+        } => record_answer(&year, &normalize_day(&day), part, answer),
+        Command::Reject {
+            year,
+            day,
+            part,
+            answer,
+            hint,
+        } => record_rejection(&year, &normalize_day(&day), part, answer, hint),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit_answer(&year, &normalize_day(&day), part, &answer).await,
+        Command::List { year } => list(year.as_deref()),
+        Command::Status { year, day } => {
+            let config = load_config()?;
+            let year = year.unwrap_or(config.current_year);
+            let day = day.map(|d| normalize_day(&d)).unwrap_or(config.current_day);
+            status(&year, &day)
+        }
+    }
+}
+
+async fn run(year: &str, day: &str, format: OutputFormat, options: RunOptions) -> Result<ExitCode> {
+    let mut on_event = |event: RunEvent<'_>| match (format, event) {
+        (OutputFormat::Text, RunEvent::Compiled(report)) => print_compile(report),
+        (OutputFormat::Text, RunEvent::Input(result)) => print_input(result),
+        _ => {}
+    };
+    let report = runner::run_day(year, day, options, &mut on_event).await;
+
+    let records = if report.compile.is_success() {
+        report
+            .inputs
+            .iter()
+            .map(|result| RunRecord::from_input(year, day, &report, result))
+            .collect()
+    } else {
+        vec![RunRecord::compile_failure(year, day, &report)]
+    };
+
+    match format {
+        OutputFormat::Json => {
+            for record in &records {
+                println!("{}", serde_json::to_string(record)?);
+            }
+        }
+        OutputFormat::Text if records.is_empty() => {
+            println!(
+                "No inputs provided. Add content to test.txt, tests/<case>/input.txt or input.txt"
+            );
+        }
+        OutputFormat::Text => {}
+    }
+
+    if records.iter().any(RunRecord::is_failure) {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+async fn benchmark(
+    year: &str,
+    day: &str,
+    bench: BenchOptions,
+    options: RunOptions,
+    format: OutputFormat,
+) -> Result<ExitCode> {
+    let mut on_event = |event: BenchEvent<'_>| match (format, event) {
+        (OutputFormat::Text, BenchEvent::Compiled(backend, profile)) => println!(
+            "✓ Built ({}, {}) · {} runs after {} warm-up\n",
+            profile.name(),
+            backend.name(),
+            bench.runs,
+            bench.warmup
+        ),
+        (
+            OutputFormat::Text,
+            BenchEvent::Input {
+                input,
+                baseline,
+                comparisons,
+            },
+        ) => {
+            println!("─── {} ───", input.input);
+            for line in input.table() {
+                println!("  {}", line);
+            }
+            if let Some(baseline) = baseline {
+                println!(
+                    "  vs {} ({}):",
+                    baseline.commit_label(),
+                    baseline
+                        .recorded_at
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                );
+                for comparison in &comparisons {
+                    println!("  {} {}", comparison.change().icon(), comparison.summary());
+                }
+            }
+            println!();
+        }
+        _ => {}
+    };
+    let benchmark = bench::benchmark_day(year, day, bench, options, &mut on_event)
+        .await
+        .map_err(|e| eyre!("{:#}", e))?;
+    if format == OutputFormat::Json {
+        println!("{}", serde_json::to_string(&benchmark)?);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn run_year(year: &str, jobs: usize) -> Result<ExitCode> {
+    let days = year::runnable_days(year);
+    if days.is_empty() {
+        println!("No days with solutions found for {}", year);
+        return Ok(ExitCode::SUCCESS);
+    }
+    println!(
+        "Running {} days of {} with {} jobs…\n",
+        days.len(),
+        year,
+        jobs
+    );
+    println!("{}", year::header());
+    // Rows print as days finish; the table is reprinted in order at the end
+    // only when they finished out of order
+    let mut printed = Vec::new();
+    let mut on_day = |row: &year::DayRow| {
+        println!("{}", row.text());
+        printed.push(row.day.clone());
+    };
+    let report = year::run_year(year, &load_config()?, jobs, &mut on_day).await;
+
+    if !printed.is_sorted() {
+        println!("\n{}", year::header());
+        for row in &report.rows {
+            println!("{}", row.text());
+        }
+    }
+    println!(
+        "\nTotal {} across {} days (wall clock {})",
+        format_duration(report.total_run_time()),
+        report.rows.len(),
+        format_duration(report.wall_time.unwrap_or_default())
+    );
+
+    if report.rows.iter().any(year::DayRow::is_failure) {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn bench_history(year: &str, day: &str, profile: Option<BuildProfile>) -> Result<ExitCode> {
+    let history = bench::history(year, day);
+    let mut shown = 0;
+    for (index, benchmark) in history.iter().enumerate() {
+        if profile.is_some_and(|profile| profile != benchmark.profile) {
+            continue;
+        }
+        shown += 1;
+        println!(
+            "{}  {}  {}, {} runs",
+            benchmark
+                .recorded_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M"),
+            benchmark.commit_label(),
+            benchmark.profile.name(),
+            benchmark.runs
+        );
+        for input in &benchmark.inputs {
+            let comparisons = bench::baseline(&history[..index], benchmark.profile, &input.input)
+                .map(|baseline| bench::compare(baseline, benchmark, input))
+                .unwrap_or_default();
+            match comparisons.first() {
+                Some(total) => println!(
+                    "  {} {:<8}{}",
+                    total.change().icon(),
+                    input.input,
+                    total.summary()
+                ),
+                None => println!(
+                    "    {:<8}total   {:>9}  (first benchmark)",
+                    input.input,
+                    bench::format_ms(input.total.mean)
+                ),
+            }
+            // Stages only when they moved, to keep the log short
+            for stage in comparisons
+                .iter()
+                .skip(1)
+                .filter(|c| c.change() != Change::Unchanged)
+            {
+                println!("  {} {:<8}{}", stage.change().icon(), "", stage.summary());
+            }
+        }
+    }
+    if shown == 0 {
+        println!(
+            "No benchmarks recorded for {}/{}; run `aoc bench` first",
+            year, day
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_compile(report: &RunReport) {
+    let build = format!("{}, {}", report.profile.name(), report.backend.name());
+    match &report.compile {
+        CompileOutcome::Unavailable(e) => eprintln!("✗ {}", e),
+        CompileOutcome::Failed(stderr) => {
+            eprintln!("✗ Compilation Failed\n");
+            eprintln!("{}", stderr.trim_end());
+        }
+        CompileOutcome::Compiled(compile_time) => {
+            println!(
+                "✓ Compiled ({}, {})\n",
+                format_duration(*compile_time),
+                build
+            );
+        }
+        CompileOutcome::Cached => println!("✓ Compiled (cached, {})\n", build),
+        CompileOutcome::Interpreted => {
+            println!("✓ Ready (interpreted, {})\n", report.backend.name())
+        }
+    }
+}
+
+fn print_input(result: &InputResult) {
+    println!("─── {} ───", result.title());
+    match &result.outcome {
+        Err(ExecError::Timeout(limit)) => {
+            println!("  ✗ TIMEOUT (killed after {})", format_duration(*limit));
+        }
+        Err(ExecError::OutOfMemory(limit_mb)) => println!("  ✗ OOM (exceeded {}MB)", limit_mb),
+        Err(ExecError::Failed(e)) => println!("  ✗ Error: {}", e),
+        Ok(output) => {
+            for (index, part) in [&output.part1, &output.part2].into_iter().enumerate() {
+                let label = format!("Part {}", index + 1);
+                match (part, &result.expected[index]) {
+                    (Some(part), _) => print_part(&label, part, result.is_test),
+                    (None, Some(exp)) => println!("  ✗ {}: no answer (expected: {})", label, exp),
+                    (None, None) => {}
+                }
+            }
+            for warning in &output.warnings {
+                println!("  ⚠ {}", warning);
+            }
+            for log in &output.logs {
+                println!("  ▸ {}", log);
+            }
+            for line in &output.output {
+                println!("  │ {}", line);
+            }
+            println!("  ⏱  {}", format_duration(result.run_time));
+            for (stage, time) in result.stage_breakdown() {
+                println!(
+                    "     {:<8} {:>8}  {}",
+                    stage,
+                    format_duration(time),
+                    duration_bar(time, result.run_time, STAGE_BAR_WIDTH)
+                );
+            }
+            if !output.stderr.contains("not yet implemented") && !output.stderr.trim().is_empty() {
+                println!("  stderr: {}", output.stderr.trim());
+            }
+        }
+    }
+    println!();
+}
+
+fn print_part(label: &str, part: &PartResult, is_test: bool) {
+    let icon = match part.verdict() {
+        Verdict::Pass => "✓",
+        Verdict::Fail => "✗",
+        Verdict::Unknown if is_test => "?",
+        Verdict::Unknown => "→",
+    };
+    let mut line = format!("  {} {}: {}", icon, label, part.answer);
+    if let (Some(exp), Verdict::Fail) = (&part.expected, part.verdict()) {
+        line.push_str(&format!(" (expected: {})", exp));
+    }
+    if let Some(warning) = &part.warning {
+        line.push_str(&format!("  ⚠ {}", warning));
+    }
+    println!("{}", line);
+}
+
+fn new(year: &str, day: &str) -> Result<ExitCode> {
+    let created = scaffold::generate_missing_structure(year, day).map_err(|e| eyre!("{:#}", e))?;
+    if created {
+        println!("Created {}/{}", year, day);
+    } else {
+        println!("{}/{} already exists", year, day);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn load_config() -> Result<Config> {
+    Config::load().map_err(|e| eyre!("{:#}", e))
+}
+
+fn session_client() -> Result<HttpClient> {
+    HttpClient::from_config(&load_config()?)
+        .map_err(|e| eyre!("{:#}", e))?
+        .ok_or_else(|| eyre!(client::MISSING_SESSION))
+}
+
+async fn example(year: &str, day: &str, block: Option<usize>) -> Result<ExitCode> {
+    let html = match puzzle::load_cached(year, day) {
+        Some(html) => html,
+        None => puzzle::fetch_description(&session_client()?, year, day)
+            .await
+            .map_err(|e| eyre!("{:#}", e))?,
+    };
+    let blocks = examples::code_blocks(&puzzle::parse(&html));
+
+    if let Some(number) = block {
+        let block = number
+            .checked_sub(1)
+            .and_then(|index| blocks.get(index))
+            .ok_or_else(|| eyre!("No code block {} (the puzzle has {})", number, blocks.len()))?;
+        let path = examples::use_block(year, day, block).map_err(|e| eyre!("{:#}", e))?;
+        println!("Wrote part {} example to {}", block.part, path.display());
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let written = examples::fill_from_puzzle(year, day).map_err(|e| eyre!("{:#}", e))?;
+    for path in &written {
+        println!("Filled {}", path);
+    }
+    if written.is_empty() {
+        println!("Nothing to fill; example files already have content");
+    }
+    println!();
+    for (number, block) in (1..).zip(&blocks) {
+        let first = block.text.lines().next().unwrap_or_default();
+        let lines = block.text.lines().count();
+        println!(
+            "  [{}] part {} · {} lines · {}",
+            number, block.part, lines, first
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn fetch(year: &str, day: &str) -> Result<ExitCode> {
+    let client = session_client()?;
+    let outcome = download::download_input(&client, year, day, Utc::now())
+        .await
+        .map_err(|e| eyre!("{:#}", e))?;
+    match outcome {
+        DownloadOutcome::Downloaded { bytes } => {
+            println!("Downloaded {}/{} input ({} bytes)", year, day, bytes);
+        }
+        DownloadOutcome::AlreadyPresent => println!("{}/{}/input.txt already exists", year, day),
+        DownloadOutcome::Locked { unlocks_at } => {
+            println!(
+                "{}/{} unlocks at {}",
+                year,
+                day,
+                unlocks_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+            );
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn record_answer(year: &str, day: &str, part: u8, answer: String) -> Result<ExitCode> {
+    let mut answers = Answers::load(year, day).map_err(|e| eyre!("{:#}", e))?;
+    answers.set(part, answer).map_err(|e| eyre!("{:#}", e))?;
+    answers.save(year, day).map_err(|e| eyre!("{:#}", e))?;
+    println!("Recorded {}/{} part {}", year, day, part);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn record_rejection(
+    year: &str,
+    day: &str,
+    part: u8,
+    answer: String,
+    hint: Option<Hint>,
+) -> Result<ExitCode> {
+    let mut answers = Answers::load(year, day).map_err(|e| eyre!("{:#}", e))?;
+    answers
+        .reject(part, answer, hint)
+        .map_err(|e| eyre!("{:#}", e))?;
+    answers.save(year, day).map_err(|e| eyre!("{:#}", e))?;
+    println!("Recorded wrong guess for {}/{} part {}", year, day, part);
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn submit_answer(year: &str, day: &str, part: u8, answer: &str) -> Result<ExitCode> {
+    let client = session_client()?;
+    let outcome = submit::submit_answer(&client, year, day, part, answer)
+        .await
+        .map_err(|e| eyre!("{:#}", e))?;
+    println!("{}/{} part {} {}: {}", year, day, part, answer, outcome);
+    Ok(match outcome {
+        SubmitOutcome::Correct | SubmitOutcome::AlreadySolved => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+fn list(only: Option<&str>) -> Result<ExitCode> {
+    let years = discovery::discover(Path::new("."));
+    let mut shown = 0;
+    for year in years
+        .iter()
+        .filter(|year| only.is_none_or(|name| year.name == name))
+    {
+        shown += 1;
+        println!(
+            "{} ({} of {} days)",
+            year.name,
+            year.days.len(),
+            year.day_count()
+        );
+        for entry in &year.days {
+            println!(
+                "  {:02}  {:<13} {}  {}",
+                entry.day,
+                entry.layout.name(),
+                entry.path.display(),
+                entry.sources.join(" ")
+            );
+        }
+    }
+    if shown == 0 {
+        return Err(eyre!("No year directories found"));
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn status(year: &str, day: &str) -> Result<ExitCode> {
+    let status = DayStatus::read(year, day);
+    let indicator = |ready: bool| if ready { "●" } else { "○" };
+    println!("{}/{}", year, day);
+    println!("  {} Solution", indicator(status.has_solution));
+    println!("  {} Test Input", indicator(status.has_test));
+    println!("  {} Real Input", indicator(status.has_input));
+    println!(
+        "  {}{} Stars",
+        "★".repeat(status.stars as usize),
+        "☆".repeat(2 - status.stars as usize)
+    );
+    if let Some(last_run) = &status.last_run {
+        let tests = match last_run.tests {
+            Some(Verdict::Pass) => "tests pass",
+            Some(Verdict::Fail) => "tests fail",
+            Some(Verdict::Unknown) => "tests unchecked",
+            None => "no tests",
+        };
+        let run_time = last_run
+            .run_time()
+            .map(|time| format!(", {}", format_duration(time)))
+            .unwrap_or_default();
+        println!(
+            "  Last run {}: {}{}",
+            last_run
+                .ran_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M"),
+            tests,
+            run_time
+        );
+    }
+    Ok(ExitCode::SUCCESS)
+}
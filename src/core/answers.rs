@@ -0,0 +1,194 @@
+use super::day_dir;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+/// Accepted answers and rejected guesses for the real input, stored in
+/// `{year}/{day}/answers.json`.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub rejected_1: Vec<Guess>,
+    pub rejected_2: Vec<Guess>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Guess {
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// The "your answer is too high/low" hint AoC gives for rejected numbers.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Why an answer should not be submitted.
+pub enum GuessWarning {
+    AlreadyRejected,
+    /// At or above the lowest guess known to be too high
+    TooHigh(i128),
+    /// At or below the highest guess known to be too low
+    TooLow(i128),
+}
+
+impl fmt::Display for GuessWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyRejected => write!(f, "already rejected"),
+            Self::TooHigh(bound) => write!(f, "too high, must be below {}", bound),
+            Self::TooLow(bound) => write!(f, "too low, must be above {}", bound),
+        }
+    }
+}
+
+impl Answers {
+    /// Reads the day's answers; a missing file means none are known yet. A
+    /// file that does not parse is an error rather than empty answers, which
+    /// the next `save` would write over it.
+    pub fn load(year: &str, day: &str) -> Result<Self> {
+        let path = path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(s) => serde_json::from_str(&s)
+                .with_context(|| format!("Failed to parse {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, year: &str, day: &str) -> Result<()> {
+        let path = path(year, day);
+        fs::write(&path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) -> Result<()> {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => bail!("Part must be 1 or 2, got {}", part),
+        }
+        Ok(())
+    }
+
+    pub fn rejected(&self, part: u8) -> &[Guess] {
+        match part {
+            1 => &self.rejected_1,
+            2 => &self.rejected_2,
+            _ => &[],
+        }
+    }
+
+    pub fn reject(&mut self, part: u8, answer: String, hint: Option<Hint>) -> Result<()> {
+        let rejected = match part {
+            1 => &mut self.rejected_1,
+            2 => &mut self.rejected_2,
+            _ => bail!("Part must be 1 or 2, got {}", part),
+        };
+        match rejected.iter_mut().find(|guess| guess.answer == answer) {
+            Some(guess) => guess.hint = hint.or(guess.hint),
+            None => rejected.push(Guess { answer, hint }),
+        }
+        Ok(())
+    }
+
+    /// Checks an answer against the rejected guesses and the numeric bounds
+    /// their hints imply.
+    pub fn check(&self, part: u8, answer: &str) -> Option<GuessWarning> {
+        let rejected = self.rejected(part);
+        if rejected.iter().any(|guess| guess.answer == answer) {
+            return Some(GuessWarning::AlreadyRejected);
+        }
+
+        let value: i128 = answer.trim().parse().ok()?;
+        let bound = |hint: Hint| {
+            rejected
+                .iter()
+                .filter(move |guess| guess.hint == Some(hint))
+                .filter_map(|guess| guess.answer.trim().parse::<i128>().ok())
+        };
+        if let Some(upper) = bound(Hint::TooHigh).min()
+            && value >= upper
+        {
+            return Some(GuessWarning::TooHigh(upper));
+        }
+        if let Some(lower) = bound(Hint::TooLow).max()
+            && value <= lower
+        {
+            return Some(GuessWarning::TooLow(lower));
+        }
+        None
+    }
+}
+
+fn path(year: &str, day: &str) -> PathBuf {
+    day_dir(year, day).join("answers.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers_with(rejected: &[(&str, Option<Hint>)]) -> Answers {
+        let mut answers = Answers::default();
+        for (answer, hint) in rejected {
+            answers.reject(1, answer.to_string(), *hint).unwrap();
+        }
+        answers
+    }
+
+    #[test]
+    fn check_flags_a_rejected_guess() {
+        let answers = answers_with(&[("abc", None)]);
+        assert!(matches!(
+            answers.check(1, "abc"),
+            Some(GuessWarning::AlreadyRejected)
+        ));
+        assert!(answers.check(2, "abc").is_none());
+    }
+
+    #[test]
+    fn check_uses_the_tightest_bounds() {
+        let answers = answers_with(&[
+            ("500", Some(Hint::TooHigh)),
+            ("300", Some(Hint::TooHigh)),
+            ("100", Some(Hint::TooLow)),
+            ("150", Some(Hint::TooLow)),
+        ]);
+        assert!(matches!(
+            answers.check(1, "300"),
+            Some(GuessWarning::AlreadyRejected)
+        ));
+        assert!(matches!(
+            answers.check(1, "400"),
+            Some(GuessWarning::TooHigh(300))
+        ));
+        assert!(matches!(
+            answers.check(1, "120"),
+            Some(GuessWarning::TooLow(150))
+        ));
+        assert!(answers.check(1, "200").is_none());
+    }
+
+    #[test]
+    fn check_skips_bounds_for_non_numeric_answers() {
+        let answers = answers_with(&[("10", Some(Hint::TooHigh))]);
+        assert!(answers.check(1, "ABCDEF").is_none());
+    }
+
+    #[test]
+    fn reject_keeps_an_earlier_hint() {
+        let mut answers = answers_with(&[("42", Some(Hint::TooLow))]);
+        answers.reject(1, String::from("42"), None).unwrap();
+        assert_eq!(answers.rejected(1).len(), 1);
+        assert!(answers.rejected(1)[0].hint == Some(Hint::TooLow));
+    }
+}
@@ -0,0 +1,534 @@
+use super::cache;
+use super::config::{BuildMode, BuildProfile};
+use super::day_dir;
+use super::dispatch;
+use super::runner::CompileOutcome;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+
+/// Cargo builds of a profile share one target dir, and all day packages name
+/// their binary `run`, so building and copying the artifact must not interleave.
+static CARGO_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// A language toolchain that turns a day's source into something runnable.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Compiles `run.rs` as a single file
+    Rustc,
+    /// Builds through the day's Cargo.toml so dependencies resolve
+    Cargo,
+    /// `go build` on `main.go`, as a module when the day has a `go.mod`
+    Go,
+    /// Runs `main.exs` with `elixir`; there is no compile step
+    Elixir,
+    /// A `dayN/mod.rs` module behind the crate's `Runner` trait, compiled
+    /// with rustc through a generated dispatcher (the 2023 layout)
+    RunnerTrait,
+    /// A `src/day_N/main.rs` built as its `[[bin]]` of the year's shared
+    /// Cargo package (the 2025-- layout)
+    CargoBin,
+}
+
+/// How to start a prepared solution.
+pub struct Program {
+    pub command: PathBuf,
+    pub args: Vec<String>,
+    /// The day's directory; older solutions open their input files relative to it
+    pub dir: PathBuf,
+    /// Whether the memory limit applies. It is enforced through `RLIMIT_AS`,
+    /// which only suits Rust binaries: the Go and BEAM runtimes reserve large
+    /// amounts of address space at startup and would fail before running
+    /// any solution code.
+    pub memory_limit: bool,
+}
+
+impl Backend {
+    /// Picks the backend from the files in `base`: `run.rs` first, then
+    /// `main.go`, then `main.exs`. `mode` decides between rustc and cargo, or
+    /// picks Go or Elixir first when the day has that source. Falls back to
+    /// rustc so a missing `run.rs` is reported as such.
+    pub fn select(mode: BuildMode, base: &Path) -> Self {
+        Self::detect(mode, base).unwrap_or(Self::Rustc)
+    }
+
+    /// The backend for the solution in `base`, if there is one.
+    pub fn detect(mode: BuildMode, base: &Path) -> Option<Self> {
+        match mode {
+            BuildMode::Go if base.join("main.go").is_file() => return Some(Self::Go),
+            BuildMode::Elixir if base.join("main.exs").is_file() => return Some(Self::Elixir),
+            _ => {}
+        }
+        if base.join("run.rs").is_file() {
+            Some(match mode {
+                BuildMode::Rustc => Self::Rustc,
+                BuildMode::Cargo => Self::Cargo,
+                _ if manifest_has_dependencies(&base.join("Cargo.toml")) => Self::Cargo,
+                _ => Self::Rustc,
+            })
+        } else if dispatch::is_runner_module(base) {
+            Some(Self::RunnerTrait)
+        } else if cargo_bin_manifest(base).is_some() {
+            Some(Self::CargoBin)
+        } else if base.join("main.go").is_file() {
+            Some(Self::Go)
+        } else if base.join("main.exs").is_file() {
+            Some(Self::Elixir)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Rustc => "rustc",
+            Self::Cargo => "cargo",
+            Self::Go => "go",
+            Self::Elixir => "elixir",
+            Self::RunnerTrait => "runner",
+            Self::CargoBin => "cargo bin",
+        }
+    }
+}
+
+/// Builds the day's solution and returns how to run it.
+pub async fn build(
+    year: &str,
+    day: &str,
+    backend: Backend,
+    profile: BuildProfile,
+) -> Result<(CompileOutcome, Program), CompileOutcome> {
+    fs::create_dir_all(cache::cache_dir()).map_err(|e| {
+        CompileOutcome::Unavailable(format!("Failed to create binary cache: {}", e))
+    })?;
+    let base = day_dir(year, day);
+    let program = |command: PathBuf| Program {
+        command,
+        args: Vec::new(),
+        dir: base.clone(),
+        memory_limit: backend != Backend::Go,
+    };
+    match backend {
+        Backend::Rustc => build_rustc(year, day, profile, backend, &base.join("run.rs"), &[])
+            .await
+            .map(|(outcome, bin_path)| (outcome, program(bin_path))),
+        Backend::Cargo => build_cargo(year, day, profile, &base.join("Cargo.toml"), "run")
+            .await
+            .map(|(outcome, bin_path)| (outcome, program(bin_path))),
+        Backend::CargoBin => build_cargo_bin(year, day, profile, &base)
+            .await
+            .map(|(outcome, bin_path)| (outcome, program(bin_path))),
+        Backend::RunnerTrait => build_dispatcher(year, day, profile, &base)
+            .await
+            .map(|(outcome, bin_path)| (outcome, program(bin_path))),
+        Backend::Go => build_go(year, day, profile, &base)
+            .await
+            .map(|(outcome, bin_path)| (outcome, program(bin_path))),
+        Backend::Elixir => Ok((
+            CompileOutcome::Interpreted,
+            Program {
+                command: PathBuf::from("elixir"),
+                args: vec![String::from("main.exs")],
+                dir: base,
+                memory_limit: false,
+            },
+        )),
+    }
+}
+
+/// Builds the solution into the binary cache, skipping `rustc` when an
+/// identical source was already compiled with the same flags.
+async fn build_rustc(
+    year: &str,
+    day: &str,
+    profile: BuildProfile,
+    backend: Backend,
+    source_path: &Path,
+    extra_flags: &[String],
+) -> Result<(CompileOutcome, PathBuf), CompileOutcome> {
+    let mut flags = profile.rustc_flags();
+    flags.extend_from_slice(extra_flags);
+    let source = fs::read(source_path).map_err(|e| {
+        CompileOutcome::Failed(format!("Failed to read {}: {}", source_path.display(), e))
+    })?;
+    let bin_path = cache::binary_path(year, day, profile, backend, &source, &flags);
+    if bin_path.exists() {
+        return Ok((CompileOutcome::Cached, bin_path));
+    }
+
+    // Build next to the final path so an interrupted build never looks cached
+    let tmp_path = bin_path.with_extension("tmp");
+
+    let compile_start = Instant::now();
+    let compile_cmd = Command::new("rustc")
+        .arg(source_path)
+        .args(&flags)
+        .arg("-o")
+        .arg(&tmp_path)
+        .kill_on_drop(true)
+        .output()
+        .await;
+    let compile_time = compile_start.elapsed();
+
+    match compile_cmd {
+        Err(e) => Err(CompileOutcome::Unavailable(format!(
+            "Failed to start compiler: {}",
+            e
+        ))),
+        Ok(output) if !output.status.success() => Err(CompileOutcome::Failed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        )),
+        Ok(_) => {
+            fs::rename(&tmp_path, &bin_path).map_err(|e| {
+                CompileOutcome::Unavailable(format!("Failed to store binary: {}", e))
+            })?;
+            cache::prune(year, day, profile, backend, &bin_path);
+            Ok((CompileOutcome::Compiled(compile_time), bin_path))
+        }
+    }
+}
+
+/// Runs `cargo build` for the `bin` target of a manifest with a target dir
+/// shared by all days, so dependencies are compiled once. Each profile gets its own target
+/// dir: native builds set `RUSTFLAGS`, which would otherwise invalidate the
+/// release artifacts on every switch. Cargo's own fingerprinting decides
+/// whether anything is rebuilt; a build without `Compiling` lines counts as cached.
+async fn build_cargo(
+    year: &str,
+    day: &str,
+    profile: BuildProfile,
+    manifest_path: &Path,
+    bin: &str,
+) -> Result<(CompileOutcome, PathBuf), CompileOutcome> {
+    if !manifest_path.exists() {
+        return Err(CompileOutcome::Failed(format!(
+            "{} not found",
+            manifest_path.display()
+        )));
+    }
+
+    let target_dir = cache::cache_dir().join("cargo-target").join(profile.name());
+    let mut command = Command::new("cargo");
+    command
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .args(["--bin", bin, "--color", "never"])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .kill_on_drop(true);
+    if profile != BuildProfile::Debug {
+        command.arg("--release");
+    }
+    if profile == BuildProfile::Native {
+        command.env("RUSTFLAGS", "-C target-cpu=native");
+    }
+
+    let _guard = CARGO_LOCK.lock().await;
+    let compile_start = Instant::now();
+    let output = command
+        .output()
+        .await
+        .map_err(|e| CompileOutcome::Unavailable(format!("Failed to start cargo: {}", e)))?;
+    let compile_time = compile_start.elapsed();
+
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    if !output.status.success() {
+        return Err(CompileOutcome::Failed(stderr));
+    }
+
+    let profile_dir = if profile == BuildProfile::Debug {
+        "debug"
+    } else {
+        "release"
+    };
+    let bin_path = cache::cache_dir().join(format!("{}_{}_{}_cargo", year, day, profile.name()));
+    fs::copy(target_dir.join(profile_dir).join(bin), &bin_path)
+        .map_err(|e| CompileOutcome::Unavailable(format!("Failed to store binary: {}", e)))?;
+
+    let fresh = !stderr
+        .lines()
+        .any(|line| line.trim_start().starts_with("Compiling"));
+    if fresh {
+        Ok((CompileOutcome::Cached, bin_path))
+    } else {
+        Ok((CompileOutcome::Compiled(compile_time), bin_path))
+    }
+}
+
+/// Builds a `src/day_N/main.rs` through the `[[bin]]` entry of the year's
+/// manifest that points at it.
+async fn build_cargo_bin(
+    year: &str,
+    day: &str,
+    profile: BuildProfile,
+    day_dir: &Path,
+) -> Result<(CompileOutcome, PathBuf), CompileOutcome> {
+    let manifest_path = cargo_bin_manifest(day_dir).ok_or_else(|| {
+        CompileOutcome::Failed(format!("No Cargo.toml above {}", day_dir.display()))
+    })?;
+    let dir_name = day_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let source = format!("src/{}/main.rs", dir_name);
+    let manifest = fs::read_to_string(&manifest_path).unwrap_or_default();
+    let bin = bin_for_path(&manifest, &source).ok_or_else(|| {
+        CompileOutcome::Failed(format!(
+            "No [[bin]] with path = \"{}\" in {}",
+            source,
+            manifest_path.display()
+        ))
+    })?;
+    build_cargo(year, day, profile, &manifest_path, &bin).await
+}
+
+/// The manifest of the package a `src/day_N/main.rs` day belongs to.
+fn cargo_bin_manifest(day_dir: &Path) -> Option<PathBuf> {
+    let is_day = day_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("day_"));
+    let src = day_dir.parent()?;
+    if !is_day || !day_dir.join("main.rs").is_file() || src.file_name()? != "src" {
+        return None;
+    }
+    Some(src.parent()?.join("Cargo.toml")).filter(|manifest| manifest.is_file())
+}
+
+/// The name of the `[[bin]]` target whose `path` is `source`.
+fn bin_for_path(manifest: &str, source: &str) -> Option<String> {
+    let mut in_bin = false;
+    let mut name = None;
+    let mut path = None;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            if in_bin && path.as_deref() == Some(source) {
+                return name;
+            }
+            in_bin = line == "[[bin]]";
+            name = None;
+            path = None;
+        } else if in_bin && let Some((key, value)) = line.split_once('=') {
+            let value = Some(value.trim().trim_matches('"').to_string());
+            match key.trim() {
+                "name" => name = value,
+                "path" => path = value,
+                _ => {}
+            }
+        }
+    }
+    name.filter(|_| in_bin && path.as_deref() == Some(source))
+}
+
+/// Generates the `Runner` dispatcher for a day module into the cache and
+/// compiles it like a `run.rs`, with the edition of the module's crate.
+async fn build_dispatcher(
+    year: &str,
+    day: &str,
+    profile: BuildProfile,
+    module_dir: &Path,
+) -> Result<(CompileOutcome, PathBuf), CompileOutcome> {
+    let source = dispatch::generate(module_dir).map_err(CompileOutcome::Failed)?;
+    let source_path = cache::cache_dir().join(format!("{}_{}_dispatch.rs", year, day));
+    fs::write(&source_path, source)
+        .map_err(|e| CompileOutcome::Unavailable(format!("Failed to write dispatcher: {}", e)))?;
+
+    let manifest = fs::read_to_string(module_dir.join("../../Cargo.toml")).unwrap_or_default();
+    let edition = manifest
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("edition"))
+        .filter_map(|rest| rest.trim().strip_prefix('='))
+        .map(|value| value.trim().trim_matches('"').to_string())
+        .next()
+        .unwrap_or_else(|| String::from("2021"));
+    let flags = [String::from("--edition"), edition];
+    build_rustc(
+        year,
+        day,
+        profile,
+        Backend::RunnerTrait,
+        &source_path,
+        &flags,
+    )
+    .await
+}
+
+/// Runs `go build` in the day's directory into the binary cache. With a
+/// `go.mod` the directory is built as a module, otherwise `main.go` alone;
+/// the cache key covers every file that build reads. Go ignores the build
+/// profile; its binaries are always optimized.
+async fn build_go(
+    year: &str,
+    day: &str,
+    profile: BuildProfile,
+    base: &Path,
+) -> Result<(CompileOutcome, PathBuf), CompileOutcome> {
+    let has_module = base.join("go.mod").is_file();
+    let mut source = fs::read(base.join("main.go")).map_err(|e| {
+        CompileOutcome::Failed(format!(
+            "Failed to read {}: {}",
+            base.join("main.go").display(),
+            e
+        ))
+    })?;
+    if has_module {
+        source = go_module_source(base);
+    }
+    let bin_path = cache::binary_path(year, day, profile, Backend::Go, &source, &[]);
+    if bin_path.exists() {
+        return Ok((CompileOutcome::Cached, bin_path));
+    }
+
+    let tmp_path = bin_path.with_extension("tmp");
+    let mut command = Command::new("go");
+    command
+        .arg("build")
+        .arg("-o")
+        .arg(&tmp_path)
+        .current_dir(base)
+        .kill_on_drop(true);
+    if !has_module {
+        command.arg("main.go");
+    }
+
+    let compile_start = Instant::now();
+    let output = command
+        .output()
+        .await
+        .map_err(|e| CompileOutcome::Unavailable(format!("Failed to start go: {}", e)))?;
+    let compile_time = compile_start.elapsed();
+
+    if !output.status.success() {
+        return Err(CompileOutcome::Failed(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ));
+    }
+    fs::rename(&tmp_path, &bin_path)
+        .map_err(|e| CompileOutcome::Unavailable(format!("Failed to store binary: {}", e)))?;
+    cache::prune(year, day, profile, Backend::Go, &bin_path);
+    Ok((CompileOutcome::Compiled(compile_time), bin_path))
+}
+
+/// Every `.go` file of a module's directory plus `go.mod` and `go.sum`,
+/// each behind its name so renaming a file changes the result.
+fn go_module_source(base: &Path) -> Vec<u8> {
+    let mut files: Vec<PathBuf> = fs::read_dir(base)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "go")
+                || path.ends_with("go.mod")
+                || path.ends_with("go.sum")
+        })
+        .collect();
+    files.sort();
+    let mut source = Vec::new();
+    for path in files {
+        source.extend(path.file_name().unwrap_or_default().as_encoded_bytes());
+        source.push(0);
+        source.extend(fs::read(&path).unwrap_or_default());
+    }
+    source
+}
+
+/// Whether a manifest's `[dependencies]` table has any entries.
+fn manifest_has_dependencies(manifest_path: &Path) -> bool {
+    let Ok(manifest) = fs::read_to_string(manifest_path) else {
+        return false;
+    };
+    let mut in_dependencies = false;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            if line.starts_with("[dependencies.") {
+                return true;
+            }
+            in_dependencies = line == "[dependencies]";
+        } else if in_dependencies && !line.is_empty() && !line.starts_with('#') {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_bin_for_a_day_source() {
+        let manifest = r#"[package]
+name = "aoc_2025"
+
+[[bin]]
+name = "day1"
+path = "src/day_1/main.rs"
+
+[[bin]]
+path = "src/day_2/main.rs"
+name = "day2"
+"#;
+        assert_eq!(
+            bin_for_path(manifest, "src/day_1/main.rs").as_deref(),
+            Some("day1")
+        );
+        assert_eq!(
+            bin_for_path(manifest, "src/day_2/main.rs").as_deref(),
+            Some("day2")
+        );
+        assert_eq!(bin_for_path(manifest, "src/day_3/main.rs"), None);
+    }
+
+    fn day(test: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_backend_{}_{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (name, content) in files {
+            fs::write(dir.join(name), content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn build_mode_picks_between_go_and_elixir() {
+        let both = day("both", &[("main.go", ""), ("main.exs", "")]);
+        assert!(Backend::detect(BuildMode::Auto, &both) == Some(Backend::Go));
+        assert!(Backend::detect(BuildMode::Go, &both) == Some(Backend::Go));
+        assert!(Backend::detect(BuildMode::Elixir, &both) == Some(Backend::Elixir));
+
+        // A mode for a language the day lacks falls back to detection
+        let rust = day("rust", &[("run.rs", "")]);
+        assert!(Backend::detect(BuildMode::Elixir, &rust) == Some(Backend::Rustc));
+        let go = day("go", &[("main.go", "")]);
+        assert!(Backend::detect(BuildMode::Elixir, &go) == Some(Backend::Go));
+        assert!(Backend::detect(BuildMode::Cargo, &go) == Some(Backend::Go));
+    }
+
+    #[test]
+    fn go_module_source_covers_every_file_the_build_reads() {
+        let dir = day(
+            "go_module",
+            &[
+                ("main.go", "package main"),
+                ("grid.go", "package main // v1"),
+                ("go.mod", "module day"),
+                ("input.txt", "1"),
+            ],
+        );
+        let original = go_module_source(&dir);
+
+        fs::write(dir.join("input.txt"), "2").unwrap();
+        assert_eq!(go_module_source(&dir), original);
+
+        fs::write(dir.join("grid.go"), "package main // v2").unwrap();
+        let edited = go_module_source(&dir);
+        assert_ne!(edited, original);
+
+        fs::write(dir.join("go.sum"), "example.com v1.0.0 h1:x").unwrap();
+        assert_ne!(go_module_source(&dir), edited);
+    }
+}
@@ -0,0 +1,745 @@
+use super::backend::{self, Backend};
+use super::config::{BuildProfile, RunOptions};
+use super::protocol::Transcript;
+use super::runner::{self, CompileOutcome};
+use super::{day_dir, format_duration, inputs};
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+
+/// Two-sided 95% critical values of Student's t for 1 to 30 degrees of freedom
+const T_CRITICAL: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+/// Smaller relative changes are not flagged even when they are significant
+const MIN_CHANGE: f64 = 0.03;
+
+/// How often a benchmark runs each input, saved in config.json.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct BenchOptions {
+    /// Measured runs per input
+    pub runs: u32,
+    /// Unmeasured runs before them, to warm file and CPU caches
+    pub warmup: u32,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            runs: 20,
+            warmup: 3,
+        }
+    }
+}
+
+/// Summary of repeated measurements, in milliseconds.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        ms.sort_by(f64::total_cmp);
+        let n = ms.len();
+        let median = if n.is_multiple_of(2) {
+            (ms[n / 2 - 1] + ms[n / 2]) / 2.0
+        } else {
+            ms[n / 2]
+        };
+        let mean = ms.iter().sum::<f64>() / n as f64;
+        // Sample standard deviation; a single run has none
+        let variance = if n > 1 {
+            ms.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        // Nearest-rank percentile
+        let p95 = ms[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+        Some(Self {
+            min: ms[0],
+            median,
+            mean,
+            p95,
+            stddev: variance.sqrt(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct StageStats {
+    pub stage: String,
+    pub stats: Stats,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InputBench {
+    /// "test", a `tests/<case>` name, or "input"
+    pub input: String,
+    pub is_test: bool,
+    /// Wall-clock time of the whole process
+    pub total: Stats,
+    /// Stages the solution timed itself, e.g. parse, part1 and part2
+    pub stages: Vec<StageStats>,
+}
+
+impl InputBench {
+    /// A table of the input's statistics: a header, the total and each stage.
+    pub fn table(&self) -> Vec<String> {
+        let row = |label: &str, stats: &Stats| {
+            let cells: Vec<String> = [stats.min, stats.median, stats.mean, stats.p95, stats.stddev]
+                .iter()
+                .map(|ms| format!("{:>9}", format_ms(*ms)))
+                .collect();
+            format!("{:<8}{}", label, cells.join(""))
+        };
+        let mut lines = vec![format!(
+            "{:<8}{:>9}{:>9}{:>9}{:>9}{:>9}",
+            "", "min", "median", "mean", "p95", "stddev"
+        )];
+        lines.push(row("total", &self.total));
+        for stage in &self.stages {
+            lines.push(row(&stage.stage, &stage.stats));
+        }
+        lines
+    }
+}
+
+/// One benchmark of a day. The latest is stored in `{year}/{day}/bench.json`
+/// and every one is appended to `bench_history.jsonl` next to it.
+#[derive(Serialize, Deserialize)]
+pub struct Benchmark {
+    pub year: String,
+    pub day: String,
+    pub profile: BuildProfile,
+    pub backend: String,
+    pub runs: u32,
+    pub warmup: u32,
+    /// Short hash of the checked-out commit, if the repo is a git checkout
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes
+    pub dirty: bool,
+    pub recorded_at: DateTime<Utc>,
+    pub inputs: Vec<InputBench>,
+}
+
+impl Benchmark {
+    pub fn load(year: &str, day: &str) -> Option<Self> {
+        fs::read_to_string(path(year, day))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+    }
+
+    /// Saves the benchmark as the day's latest and appends it to its history.
+    pub fn save(&self) -> Result<()> {
+        let path = path(&self.year, &self.day);
+        fs::write(&path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        let history = history_path(&self.year, &self.day);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&history)
+            .with_context(|| format!("Failed to open {}", history.display()))?;
+        writeln!(file, "{}", serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", history.display()))
+    }
+
+    /// The commit label shown in comparisons, e.g. "3a6355a+" for a dirty tree.
+    pub fn commit_label(&self) -> String {
+        match &self.commit {
+            Some(commit) if self.dirty => format!("{}+", commit),
+            Some(commit) => commit.clone(),
+            None => String::from("no commit"),
+        }
+    }
+
+    pub fn input(&self, name: &str) -> Option<&InputBench> {
+        self.inputs.iter().find(|input| input.input == name)
+    }
+
+    /// The real input's statistics, if `input.txt` was benchmarked.
+    pub fn real_input(&self) -> Option<&InputBench> {
+        self.inputs.iter().find(|input| !input.is_test)
+    }
+}
+
+/// Every benchmark recorded for the day, oldest first.
+pub fn history(year: &str, day: &str) -> Vec<Benchmark> {
+    fs::read_to_string(history_path(year, day))
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The most recent benchmark in `history` with the same profile that ran `input`.
+pub fn baseline<'a>(
+    history: &'a [Benchmark],
+    profile: BuildProfile,
+    input: &str,
+) -> Option<&'a Benchmark> {
+    history
+        .iter()
+        .rev()
+        .find(|benchmark| benchmark.profile == profile && benchmark.input(input).is_some())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Slower,
+    Faster,
+    Unchanged,
+}
+
+impl Change {
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Slower => "▲",
+            Self::Faster => "▼",
+            Self::Unchanged => "·",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Slower => "slower",
+            Self::Faster => "faster",
+            Self::Unchanged => "within noise",
+        }
+    }
+}
+
+/// One measurement of an input, total or stage, against the same one in a baseline.
+pub struct Comparison {
+    pub stage: String,
+    pub before: Stats,
+    pub after: Stats,
+    pub before_runs: u32,
+    pub after_runs: u32,
+}
+
+impl Comparison {
+    /// Change of the mean relative to the baseline, e.g. 0.12 for 12% slower.
+    pub fn relative(&self) -> f64 {
+        if self.before.mean == 0.0 {
+            return 0.0;
+        }
+        (self.after.mean - self.before.mean) / self.before.mean
+    }
+
+    /// Welch's t statistic for the difference in means.
+    pub fn t_statistic(&self) -> f64 {
+        let variance = |stats: &Stats, runs: u32| stats.stddev.powi(2) / runs.max(1) as f64;
+        let error = (variance(&self.before, self.before_runs)
+            + variance(&self.after, self.after_runs))
+        .sqrt();
+        let difference = self.after.mean - self.before.mean;
+        if error == 0.0 {
+            // No spread at all: any difference is as certain as it gets
+            return if difference == 0.0 {
+                0.0
+            } else {
+                difference.signum() * f64::INFINITY
+            };
+        }
+        difference / error
+    }
+
+    /// Welch–Satterthwaite degrees of freedom of `t_statistic`; the pooled
+    /// `n1 + n2 - 2` when neither side has any spread.
+    pub fn degrees_of_freedom(&self) -> f64 {
+        let n1 = self.before_runs.max(2) as f64;
+        let n2 = self.after_runs.max(2) as f64;
+        let v1 = self.before.stddev.powi(2) / n1;
+        let v2 = self.after.stddev.powi(2) / n2;
+        let denominator = v1.powi(2) / (n1 - 1.0) + v2.powi(2) / (n2 - 1.0);
+        if denominator == 0.0 {
+            return n1 + n2 - 2.0;
+        }
+        (v1 + v2).powi(2) / denominator
+    }
+
+    /// A change needs two runs on each side, since a single run has no
+    /// spread to judge noise by, a |t| beyond the 95% critical value for its
+    /// degrees of freedom, and at least `MIN_CHANGE` relative size.
+    pub fn change(&self) -> Change {
+        let relative = self.relative();
+        if self.before_runs < 2
+            || self.after_runs < 2
+            || self.t_statistic().abs() < t_critical(self.degrees_of_freedom())
+            || relative.abs() < MIN_CHANGE
+        {
+            Change::Unchanged
+        } else if relative > 0.0 {
+            Change::Slower
+        } else {
+            Change::Faster
+        }
+    }
+
+    /// E.g. "total   14.31ms → 16.12ms  +12.6%  slower".
+    pub fn summary(&self) -> String {
+        format!(
+            "{:<8}{:>9} → {:<9}{:>+7.1}%  {}",
+            self.stage,
+            format_ms(self.before.mean),
+            format_ms(self.after.mean),
+            self.relative() * 100.0,
+            self.change().label()
+        )
+    }
+}
+
+/// The 95% two-sided critical value for `df` degrees of freedom. A fractional
+/// `df` rounds down and larger ones use the next tabulated value below, which
+/// errs towards calling a change noise.
+fn t_critical(df: f64) -> f64 {
+    match df.floor() as usize {
+        0 => T_CRITICAL[0],
+        df @ 1..=30 => T_CRITICAL[df - 1],
+        31..=39 => T_CRITICAL[29],
+        40..=59 => 2.021,
+        60..=119 => 2.000,
+        _ => 1.980,
+    }
+}
+
+/// An input's benchmark against the same input in `baseline`: the total
+/// first, then every stage both of them timed.
+pub fn compare(baseline: &Benchmark, current: &Benchmark, input: &InputBench) -> Vec<Comparison> {
+    let Some(before) = baseline.input(&input.input) else {
+        return Vec::new();
+    };
+    let comparison = |stage: &str, before: Stats, after: Stats| Comparison {
+        stage: stage.to_string(),
+        before,
+        after,
+        before_runs: baseline.runs,
+        after_runs: current.runs,
+    };
+    let mut comparisons = vec![comparison("total", before.total, input.total)];
+    for stage in &input.stages {
+        if let Some(previous) = before.stages.iter().find(|s| s.stage == stage.stage) {
+            comparisons.push(comparison(&stage.stage, previous.stats, stage.stats));
+        }
+    }
+    comparisons
+}
+
+/// Progress reported while `benchmark_day` runs.
+pub enum BenchEvent<'a> {
+    Phase(String),
+    /// Building finished with this backend and profile
+    Compiled(Backend, BuildProfile),
+    /// An input finished; the comparisons are against the previous benchmark
+    /// of the same input and profile, which is given if there is one
+    Input {
+        input: &'a InputBench,
+        baseline: Option<&'a Benchmark>,
+        comparisons: Vec<Comparison>,
+    },
+}
+
+/// Formats milliseconds the way run times are shown elsewhere, keeping the
+/// precision small timings need.
+pub fn format_ms(ms: f64) -> String {
+    if ms >= 1000.0 {
+        format_duration(Duration::from_secs_f64(ms / 1000.0))
+    } else if ms >= 1.0 {
+        format!("{:.2}ms", ms)
+    } else {
+        format!("{:.0}µs", ms * 1000.0)
+    }
+}
+
+/// Builds the day optimized and runs every input `warmup + runs` times,
+/// keeping statistics over the last `runs`. Debug builds are benchmarked as
+/// release. The result is recorded with the current commit and compared
+/// against the day's history.
+pub async fn benchmark_day(
+    year: &str,
+    day: &str,
+    bench: BenchOptions,
+    options: RunOptions,
+    on_event: &mut (dyn FnMut(BenchEvent<'_>) + Send),
+) -> Result<Benchmark> {
+    if bench.runs == 0 {
+        bail!("A benchmark needs at least one run");
+    }
+    let profile = match options.profile {
+        BuildProfile::Debug => BuildProfile::Release,
+        profile => profile,
+    };
+    let backend = Backend::select(options.build_mode, &day_dir(year, day));
+
+    on_event(BenchEvent::Phase(format!(
+        "Compiling {} with {}",
+        profile.name(),
+        backend.name()
+    )));
+    let program = match backend::build(year, day, backend, profile).await {
+        Ok((_, program)) => program,
+        Err(CompileOutcome::Failed(stderr)) => bail!("Compilation failed:\n{}", stderr.trim_end()),
+        Err(CompileOutcome::Unavailable(e)) => bail!("{}", e),
+        Err(_) => bail!("Compilation failed"),
+    };
+    on_event(BenchEvent::Compiled(backend, profile));
+
+    let cases = inputs::load_cases(year, day);
+    if cases.is_empty() {
+        bail!("No inputs to benchmark; add test.txt, tests/<case>/input.txt or input.txt");
+    }
+
+    let history = history(year, day);
+    let (commit, dirty) = git_state().await;
+    let mut benchmark = Benchmark {
+        year: year.to_string(),
+        day: day.to_string(),
+        profile,
+        backend: backend.name().to_string(),
+        runs: bench.runs,
+        warmup: bench.warmup,
+        commit,
+        dirty,
+        recorded_at: Utc::now(),
+        inputs: Vec::new(),
+    };
+    for case in cases {
+        let mut totals = Vec::new();
+        let mut stages: Vec<(String, Vec<Duration>)> = Vec::new();
+        for run in 0..bench.warmup + bench.runs {
+            let phase = if run < bench.warmup {
+                format!("Warming up {} ({}/{})", case.name, run + 1, bench.warmup)
+            } else {
+                format!(
+                    "Benchmarking {} ({}/{})",
+                    case.name,
+                    run - bench.warmup + 1,
+                    bench.runs
+                )
+            };
+            on_event(BenchEvent::Phase(phase));
+
+            let start = Instant::now();
+            let (stdout, _) = runner::execute(
+                &program,
+                &case.content,
+                case.only_part,
+                options.limits,
+                &mut |_, _| {},
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("{}: {}", case.name, e))?;
+            let elapsed = start.elapsed();
+            if run < bench.warmup {
+                continue;
+            }
+
+            totals.push(elapsed);
+            for (stage, time) in Transcript::read(&stdout).timings {
+                match stages.iter_mut().find(|(name, _)| *name == stage) {
+                    Some((_, samples)) => samples.push(time),
+                    None => stages.push((stage, vec![time])),
+                }
+            }
+        }
+
+        let Some(total) = Stats::from_samples(&totals) else {
+            continue;
+        };
+        benchmark.inputs.push(InputBench {
+            input: case.name,
+            is_test: case.is_test,
+            total,
+            stages: stages
+                .into_iter()
+                .filter_map(|(stage, samples)| {
+                    Some(StageStats {
+                        stage,
+                        stats: Stats::from_samples(&samples)?,
+                    })
+                })
+                .collect(),
+        });
+        let input = benchmark.inputs.last().unwrap();
+        let baseline = baseline(&history, profile, &input.input);
+        on_event(BenchEvent::Input {
+            input,
+            baseline,
+            comparisons: baseline
+                .map(|baseline| compare(baseline, &benchmark, input))
+                .unwrap_or_default(),
+        });
+    }
+
+    benchmark.save()?;
+    Ok(benchmark)
+}
+
+/// The checked-out commit and whether the tree has uncommitted changes;
+/// `(None, false)` outside a git checkout. `config.json` does not count: the
+/// dashboard rewrites it all the time and it does not affect the solutions.
+async fn git_state() -> (Option<String>, bool) {
+    let git = |args: &'static [&'static str]| async move {
+        let output = Command::new("git").args(args).output().await.ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]).await else {
+        return (None, false);
+    };
+    let dirty = git(&[
+        "status",
+        "--porcelain",
+        "--untracked-files=no",
+        "--",
+        ":/",
+        ":(exclude)config.json",
+    ])
+    .await
+    .is_some_and(|status| !status.is_empty());
+    (Some(commit), dirty)
+}
+
+fn path(year: &str, day: &str) -> PathBuf {
+    day_dir(year, day).join("bench.json")
+}
+
+fn history_path(year: &str, day: &str) -> PathBuf {
+    day_dir(year, day).join("bench_history.jsonl")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn stats_of_an_odd_sample() {
+        let stats = Stats::from_samples(&ms(&[5, 1, 3, 2, 4])).unwrap();
+        assert!(close(stats.min, 1.0));
+        assert!(close(stats.median, 3.0));
+        assert!(close(stats.mean, 3.0));
+        assert!(close(stats.p95, 5.0));
+        assert!(close(stats.stddev, 2.5f64.sqrt()));
+    }
+
+    #[test]
+    fn stats_of_an_even_sample() {
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2])).unwrap();
+        assert!(close(stats.median, 2.5));
+        assert!(close(stats.mean, 2.5));
+    }
+
+    #[test]
+    fn p95_is_the_nearest_rank() {
+        let samples: Vec<u64> = (1..=20).collect();
+        let stats = Stats::from_samples(&ms(&samples)).unwrap();
+        assert!(close(stats.p95, 19.0));
+    }
+
+    #[test]
+    fn a_single_run_has_no_spread() {
+        let stats = Stats::from_samples(&ms(&[7])).unwrap();
+        assert!(close(stats.median, 7.0));
+        assert!(close(stats.p95, 7.0));
+        assert!(close(stats.stddev, 0.0));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn formats_milliseconds() {
+        assert_eq!(format_ms(0.25), "250µs");
+        assert_eq!(format_ms(14.314), "14.31ms");
+        assert_eq!(format_ms(1500.0), "1.50s");
+    }
+
+    fn stats(mean: f64, stddev: f64) -> Stats {
+        Stats {
+            min: mean,
+            median: mean,
+            mean,
+            p95: mean,
+            stddev,
+        }
+    }
+
+    fn comparison(before: Stats, after: Stats) -> Comparison {
+        Comparison {
+            stage: String::from("total"),
+            before,
+            after,
+            before_runs: 20,
+            after_runs: 20,
+        }
+    }
+
+    #[test]
+    fn welch_t_statistic() {
+        let slower = comparison(stats(10.0, 1.0), stats(11.0, 1.0));
+        assert!(close(slower.relative(), 0.1));
+        assert!(close(slower.t_statistic(), 10.0f64.sqrt()));
+
+        // Unequal spreads and run counts each weigh their own variance
+        let mut uneven = comparison(stats(10.0, 2.0), stats(9.0, 1.0));
+        uneven.before_runs = 10;
+        uneven.after_runs = 40;
+        assert!(close(uneven.t_statistic(), -1.0 / (0.4f64 + 0.025).sqrt()));
+    }
+
+    #[test]
+    fn flags_only_significant_and_large_changes() {
+        let cases = [
+            (stats(10.0, 1.0), stats(11.0, 1.0), Change::Slower),
+            (stats(10.0, 1.0), stats(8.0, 1.0), Change::Faster),
+            // 2% with t ≈ 0.6: noise
+            (stats(10.0, 1.0), stats(10.2, 1.0), Change::Unchanged),
+            // Significant, but under the 3% floor
+            (stats(10.0, 0.01), stats(10.2, 0.01), Change::Unchanged),
+            // Large, but too noisy to tell
+            (stats(10.0, 10.0), stats(15.0, 10.0), Change::Unchanged),
+        ];
+        for (before, after, change) in cases {
+            assert!(comparison(before, after).change() == change);
+        }
+    }
+
+    #[test]
+    fn welch_degrees_of_freedom() {
+        // Equal spreads and run counts give the pooled n1 + n2 - 2
+        let even = comparison(stats(10.0, 1.0), stats(11.0, 1.0));
+        assert!(close(even.degrees_of_freedom(), 38.0));
+        // One noisy side dominates and brings df down to its own n - 1
+        let mut uneven = comparison(stats(10.0, 5.0), stats(9.0, 0.001));
+        uneven.before_runs = 5;
+        uneven.after_runs = 40;
+        assert!((uneven.degrees_of_freedom() - 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn critical_value_follows_degrees_of_freedom() {
+        assert!(close(t_critical(0.5), 12.706));
+        assert!(close(t_critical(4.0), 2.776));
+        assert!(close(t_critical(4.9), 2.776));
+        assert!(close(t_critical(30.0), 2.042));
+        assert!(close(t_critical(35.0), 2.042));
+        assert!(close(t_critical(1000.0), 1.980));
+    }
+
+    #[test]
+    fn small_samples_need_a_larger_t() {
+        // t ≈ 2.5 is significant with 20 runs a side but not with 3
+        let many = comparison(stats(10.0, 1.0), stats(10.0 + 2.5 * 0.1f64.sqrt(), 1.0));
+        assert!(close(many.t_statistic(), 2.5));
+        assert!(many.change() == Change::Slower);
+
+        let mut few = comparison(
+            stats(10.0, 1.0),
+            stats(10.0 + 2.5 * (2.0f64 / 3.0).sqrt(), 1.0),
+        );
+        few.before_runs = 3;
+        few.after_runs = 3;
+        assert!(close(few.t_statistic(), 2.5));
+        assert!(few.change() == Change::Unchanged);
+    }
+
+    #[test]
+    fn single_runs_are_never_significant() {
+        let mut single = comparison(stats(10.0, 0.0), stats(20.0, 0.0));
+        single.before_runs = 1;
+        single.after_runs = 1;
+        assert!(single.t_statistic() == f64::INFINITY);
+        assert!(single.change() == Change::Unchanged);
+        single.before_runs = 20;
+        assert!(single.change() == Change::Unchanged);
+    }
+
+    #[test]
+    fn identical_runs_without_spread_are_unchanged() {
+        let same = comparison(stats(5.0, 0.0), stats(5.0, 0.0));
+        assert!(close(same.t_statistic(), 0.0));
+        assert!(same.change() == Change::Unchanged);
+        let faster = comparison(stats(5.0, 0.0), stats(4.0, 0.0));
+        assert!(faster.t_statistic() == f64::NEG_INFINITY);
+        assert!(faster.change() == Change::Faster);
+    }
+
+    fn benchmark(profile: BuildProfile, inputs: &[(&str, f64)]) -> Benchmark {
+        Benchmark {
+            year: String::from("2024"),
+            day: String::from("01"),
+            profile,
+            backend: String::from("rustc"),
+            runs: 20,
+            warmup: 3,
+            commit: None,
+            dirty: false,
+            recorded_at: Utc::now(),
+            inputs: inputs
+                .iter()
+                .map(|(input, mean)| InputBench {
+                    input: input.to_string(),
+                    is_test: *input != "input",
+                    total: stats(*mean, 1.0),
+                    stages: vec![StageStats {
+                        stage: String::from("part1"),
+                        stats: stats(*mean / 2.0, 0.5),
+                    }],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn baseline_is_the_latest_with_the_same_profile_and_input() {
+        let history = [
+            benchmark(BuildProfile::Release, &[("input", 10.0)]),
+            benchmark(BuildProfile::Release, &[("test", 1.0)]),
+            benchmark(BuildProfile::Native, &[("input", 9.0)]),
+        ];
+        let found = baseline(&history, BuildProfile::Release, "input").unwrap();
+        assert!(close(found.input("input").unwrap().total.mean, 10.0));
+        assert!(baseline(&history, BuildProfile::Debug, "input").is_none());
+    }
+
+    #[test]
+    fn compares_the_total_and_shared_stages() {
+        let before = benchmark(BuildProfile::Release, &[("input", 10.0)]);
+        let after = benchmark(BuildProfile::Release, &[("input", 12.0), ("test", 1.0)]);
+        let comparisons = compare(&before, &after, after.input("input").unwrap());
+        let stages: Vec<&str> = comparisons.iter().map(|c| c.stage.as_str()).collect();
+        assert_eq!(stages, ["total", "part1"]);
+        assert!(close(comparisons[0].relative(), 0.2));
+        assert!(compare(&before, &after, after.input("test").unwrap()).is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+use super::backend::Backend;
+use super::config::BuildProfile;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Shared directory for compiled solution binaries.
+pub fn cache_dir() -> PathBuf {
+    std::env::temp_dir().join("aoc_cache")
+}
+
+/// Location of the binary `backend` built from `source` with `flags`.
+/// Binaries are named `{year}_{day}_{profile}_{backend}_{hash}` so stale builds
+/// of the same day, profile and backend can be pruned without evicting the
+/// others.
+pub fn binary_path(
+    year: &str,
+    day: &str,
+    profile: BuildProfile,
+    backend: Backend,
+    source: &[u8],
+    flags: &[String],
+) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    flags.hash(&mut hasher);
+    cache_dir().join(format!(
+        "{}{:016x}",
+        prefix(year, day, profile, backend),
+        hasher.finish()
+    ))
+}
+
+/// Removes every cached binary of the day, profile and backend except `keep`.
+/// Only names ending in a source hash match, which leaves the cargo copies and
+/// the `.tmp` files of builds still in progress alone.
+pub fn prune(year: &str, day: &str, profile: BuildProfile, backend: Backend, keep: &Path) {
+    let prefix = prefix(year, day, profile, backend);
+    let Ok(entries) = fs::read_dir(cache_dir()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_stale = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix(&prefix))
+            .is_some_and(is_hash);
+        if is_stale && path != keep {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn prefix(year: &str, day: &str, profile: BuildProfile, backend: Backend) -> String {
+    format!("{}_{}_{}_{}_", year, day, profile.name(), backend.name())
+}
+
+fn is_hash(suffix: &str) -> bool {
+    suffix.len() == 16 && suffix.bytes().all(|b| b.is_ascii_hexdigit())
+}
@@ -0,0 +1,144 @@
+use super::discovery;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone, Utc};
+
+/// Puzzles unlock at midnight US Eastern, which is UTC-5 all of December.
+const EASTERN_OFFSET_SECS: i32 = -5 * 3600;
+
+/// Number of puzzles in an event; from 2025 on AoC runs for 12 days.
+pub fn days_in_year(year: i32) -> u32 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Puzzles unlock at midnight US Eastern (UTC-5 all of December). `year` may
+/// be a year directory such as "2025--"; its event year is used.
+pub fn unlock_time(year: &str, day: &str) -> Option<DateTime<Utc>> {
+    let year = discovery::event_year(year)?;
+    let day: u32 = day.parse().ok()?;
+    Utc.with_ymd_and_hms(year, 12, day, 5, 0, 0).single()
+}
+
+pub fn is_unlocked(year: &str, day: &str, now: DateTime<Utc>) -> bool {
+    unlock_time(year, day).is_some_and(|unlock| now >= unlock)
+}
+
+/// The puzzle that unlocked most recently, as `("2025", "03")`, while an event
+/// is running; `None` outside of December's puzzle days.
+pub fn puzzle_today(now: DateTime<Utc>) -> Option<(String, String)> {
+    let eastern = now.with_timezone(&eastern());
+    let (year, day) = (eastern.year(), eastern.day());
+    if eastern.month() != 12 || day > days_in_year(year) {
+        return None;
+    }
+    Some((year.to_string(), format!("{:02}", day)))
+}
+
+/// The next puzzle to unlock after `now` and when it does.
+pub fn next_unlock(now: DateTime<Utc>) -> (String, String, DateTime<Utc>) {
+    let eastern = now.with_timezone(&eastern());
+    let year = eastern.year();
+    let (year, day) = match puzzle_today(now) {
+        Some(_) if eastern.day() < days_in_year(year) => (year, eastern.day() + 1),
+        Some(_) => (year + 1, 1),
+        None if eastern.month() == 12 => (year + 1, 1),
+        None => (year, 1),
+    };
+    let year = year.to_string();
+    let day = format!("{:02}", day);
+    let unlocks_at = unlock_time(&year, &day).unwrap_or(now);
+    (year, day, unlocks_at)
+}
+
+/// Formats a countdown as `2d 03:04:05`, or `03:04:05` under a day.
+pub fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.num_seconds().max(0);
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+    if days > 0 {
+        format!("{}d {}", days, clock)
+    } else {
+        clock
+    }
+}
+
+fn eastern() -> FixedOffset {
+    FixedOffset::east_opt(EASTERN_OFFSET_SECS).expect("valid offset")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, month, day, hour, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        assert_eq!(unlock_time("2024", "03"), Some(utc(12, 3, 5, 0)));
+        assert_eq!(unlock_time("2024", "x"), None);
+        assert_eq!(unlock_time("24x", "03"), None);
+        assert_eq!(unlock_time("2024--", "03"), Some(utc(12, 3, 5, 0)));
+        assert_eq!(unlock_time("2024", "32"), None);
+        assert!(!is_unlocked("2024", "03", utc(12, 3, 4, 59)));
+        assert!(is_unlocked("2024", "03", utc(12, 3, 5, 0)));
+    }
+
+    #[test]
+    fn today_follows_the_eastern_date() {
+        assert_eq!(
+            puzzle_today(utc(12, 3, 4, 59)),
+            Some((String::from("2024"), String::from("02")))
+        );
+        assert_eq!(
+            puzzle_today(utc(12, 3, 5, 0)),
+            Some((String::from("2024"), String::from("03")))
+        );
+        assert_eq!(puzzle_today(utc(12, 26, 12, 0)), None);
+        assert_eq!(puzzle_today(utc(11, 30, 12, 0)), None);
+    }
+
+    #[test]
+    fn events_from_2025_have_twelve_days() {
+        assert_eq!(days_in_year(2024), 25);
+        assert_eq!(days_in_year(2025), 12);
+        let after_last = Utc.with_ymd_and_hms(2025, 12, 13, 12, 0, 0).unwrap();
+        assert_eq!(puzzle_today(after_last), None);
+    }
+
+    #[test]
+    fn next_unlock_moves_to_the_following_day_or_event() {
+        let (year, day, at) = next_unlock(utc(12, 3, 12, 0));
+        assert_eq!(
+            (year.as_str(), day.as_str(), at),
+            ("2024", "04", utc(12, 4, 5, 0))
+        );
+
+        let (year, day, _) = next_unlock(utc(12, 25, 12, 0));
+        assert_eq!((year.as_str(), day.as_str()), ("2025", "01"));
+
+        let (year, day, at) = next_unlock(utc(6, 1, 0, 0));
+        assert_eq!(
+            (year.as_str(), day.as_str(), at),
+            ("2024", "01", utc(12, 1, 5, 0))
+        );
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(
+            format_countdown(Duration::seconds(3 * 3600 + 4 * 60 + 5)),
+            "03:04:05"
+        );
+        assert_eq!(
+            format_countdown(Duration::seconds(2 * 86_400 + 61)),
+            "2d 00:01:01"
+        );
+        assert_eq!(format_countdown(Duration::seconds(-5)), "00:00:00");
+    }
+}
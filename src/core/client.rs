@@ -0,0 +1,199 @@
+use super::config::Config;
+use super::discovery;
+use anyhow::{Context, Result, bail};
+use std::future::Future;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "aoc-cli/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/mpiorowski/aoc)"
+);
+const SESSION_ENV: &str = "AOC_SESSION";
+/// Gitignored, unlike `config.json`, so the token cannot end up in a commit
+const SESSION_FILE: &str = ".aoc_session";
+pub const MISSING_SESSION: &str = "No session token: set AOC_SESSION or save it in .aoc_session";
+
+/// The Advent of Code endpoints the workflow needs. Implemented over HTTP by
+/// `HttpClient`; tests can implement it directly or point `HttpClient` at a stub server.
+pub trait AocClient: Send + Sync {
+    fn fetch_input(&self, year: &str, day: &str) -> impl Future<Output = Result<String>> + Send;
+
+    /// The day's puzzle page as HTML; part 2 is only included once part 1 is solved.
+    fn fetch_puzzle(&self, year: &str, day: &str) -> impl Future<Output = Result<String>> + Send;
+
+    /// Posts an answer and returns the raw HTML of the response page.
+    fn submit_answer(
+        &self,
+        year: &str,
+        day: &str,
+        part: u8,
+        answer: &str,
+    ) -> impl Future<Output = Result<String>> + Send;
+}
+
+pub struct HttpClient {
+    http: reqwest::Client,
+    base_url: String,
+    session: String,
+}
+
+impl HttpClient {
+    pub fn new(base_url: &str, session: &str) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .context("Failed to build HTTP client")?;
+        Ok(Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        })
+    }
+
+    /// Builds a client from `config.json`, taking the session token from the
+    /// `AOC_SESSION` environment variable first and the `.aoc_session` file
+    /// second. Returns `None` when no token is configured.
+    pub fn from_config(config: &Config) -> Result<Option<Self>> {
+        let session = std::env::var(SESSION_ENV)
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .or_else(|| std::fs::read_to_string(SESSION_FILE).ok())
+            .filter(|s| !s.trim().is_empty());
+        let Some(session) = session else {
+            return Ok(None);
+        };
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        Self::new(base_url, &session).map(Some)
+    }
+
+    /// The puzzle's URL; `year` may be a year directory such as "2025--".
+    fn day_url(&self, year: &str, day: &str) -> Result<String> {
+        let event =
+            discovery::event_year(year).with_context(|| format!("Invalid year {:?}", year))?;
+        let day: u32 = day
+            .parse()
+            .with_context(|| format!("Invalid day {:?}", day))?;
+        Ok(format!("{}/{}/day/{}", self.base_url, event, day))
+    }
+
+    async fn get(&self, url: &str) -> Result<String> {
+        self.send(self.http.get(url), url).await
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder, url: &str) -> Result<String> {
+        let response = request
+            .header(reqwest::header::COOKIE, format!("session={}", self.session))
+            .send()
+            .await
+            .with_context(|| format!("Request to {} failed", url))?;
+        let status = response.status();
+        let body = response.text().await.context("Failed to read response")?;
+        if !status.is_success() {
+            let reason = body.lines().next().unwrap_or_default().trim();
+            bail!("{} returned {}: {}", url, status, reason);
+        }
+        Ok(body)
+    }
+}
+
+impl AocClient for HttpClient {
+    async fn fetch_input(&self, year: &str, day: &str) -> Result<String> {
+        let url = format!("{}/input", self.day_url(year, day)?);
+        self.get(&url).await
+    }
+
+    async fn fetch_puzzle(&self, year: &str, day: &str) -> Result<String> {
+        let url = self.day_url(year, day)?;
+        self.get(&url).await
+    }
+
+    async fn submit_answer(&self, year: &str, day: &str, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/answer", self.day_url(year, day)?);
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        self.send(self.http.post(&url).form(&form), &url).await
+    }
+}
+
+/// A local HTTP server standing in for the site in tests.
+#[cfg(test)]
+pub(super) mod stub {
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    pub struct Stub {
+        pub base_url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Stub {
+        /// Serves every request with `status` and `body` until the test ends.
+        pub async fn start(status: u16, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let received = requests.clone();
+            tokio::spawn(async move {
+                while let Ok((mut stream, _)) = listener.accept().await {
+                    let request = read_request(&mut stream).await;
+                    received.lock().unwrap().push(request);
+                    let response = format!(
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                }
+            });
+            Self { base_url, requests }
+        }
+
+        /// Raw requests received so far, header names lowercased.
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> String {
+        let mut request = Vec::new();
+        let mut buf = [0; 4096];
+        while let Ok(read) = stream.read(&mut buf).await {
+            request.extend_from_slice(&buf[..read]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            let Some(header_end) = text.find("\r\n\r\n") else {
+                if read == 0 {
+                    break;
+                }
+                continue;
+            };
+            let content_length = text[..header_end]
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if read == 0 || request.len() >= header_end + 4 + content_length {
+                break;
+            }
+        }
+        let text = String::from_utf8_lossy(&request);
+        let (head, body) = text.split_once("\r\n\r\n").unwrap_or((&text, ""));
+        let mut lines = head.lines();
+        let request_line = lines.next().unwrap_or_default().to_string();
+        let headers: Vec<String> = lines
+            .map(|line| match line.split_once(':') {
+                Some((name, value)) => format!("{}:{}", name.to_lowercase(), value),
+                None => line.to_string(),
+            })
+            .collect();
+        format!(
+            "{}\r\n{}\r\n\r\n{}",
+            request_line,
+            headers.join("\r\n"),
+            body
+        )
+    }
+}
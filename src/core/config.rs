@@ -0,0 +1,161 @@
+use super::bench::BenchOptions;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+const CONFIG_PATH: &str = "config.json";
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct Config {
+    pub current_year: String,
+    pub current_day: String,
+    pub selected_year_index: usize,
+    pub selected_day_index: usize,
+    pub limits: RunLimits,
+    /// Per-day overrides keyed by "{year}/{day}", e.g. "2025/03"
+    pub day_limits: HashMap<String, DayLimits>,
+    pub profile: BuildProfile,
+    pub build_mode: BuildMode,
+    /// Per-day build modes keyed like `day_limits`, e.g. `"2024/01": "elixir"`
+    /// for a day that has both a Go and an Elixir solution
+    pub day_build_modes: HashMap<String, BuildMode>,
+    pub bench: BenchOptions,
+    /// Site root for downloads, e.g. a local stub server in tests
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Reads `config.json`; a missing file means the defaults. A file that
+    /// does not parse is an error rather than the defaults, which the next
+    /// `save` would write over it.
+    pub fn load() -> Result<Self> {
+        match fs::read_to_string(CONFIG_PATH) {
+            Ok(s) => {
+                serde_json::from_str(&s).with_context(|| format!("Failed to parse {}", CONFIG_PATH))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", CONFIG_PATH)),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(CONFIG_PATH, serde_json::to_string_pretty(self)?).context("Failed to save config")
+    }
+
+    pub fn limits_for(&self, year: &str, day: &str) -> RunLimits {
+        self.limits
+            .with_override(self.day_limits.get(&format!("{}/{}", year, day)))
+    }
+
+    pub fn run_options(&self, year: &str, day: &str) -> RunOptions {
+        RunOptions {
+            limits: self.limits_for(year, day),
+            profile: self.profile,
+            build_mode: self
+                .day_build_modes
+                .get(&format!("{}/{}", year, day))
+                .copied()
+                .unwrap_or(self.build_mode),
+        }
+    }
+}
+
+/// Everything that decides how a day is built and executed.
+#[derive(Clone, Copy)]
+pub struct RunOptions {
+    pub limits: RunLimits,
+    pub profile: BuildProfile,
+    pub build_mode: BuildMode,
+}
+
+/// Which toolchain builds a day. A mode only applies to days that have its
+/// source file; other days are built as with `Auto`.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildMode {
+    /// From the day's files: run.rs (cargo when its Cargo.toml has
+    /// dependencies, plain rustc otherwise), then main.go, then main.exs
+    #[default]
+    Auto,
+    Rustc,
+    Cargo,
+    /// main.go, for days that also have a solution in another language
+    Go,
+    /// main.exs, for days that also have a solution in another language
+    Elixir,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildProfile {
+    #[default]
+    Debug,
+    Release,
+    /// Release build tuned for the host CPU (`-C target-cpu=native`)
+    Native,
+}
+
+impl BuildProfile {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Debug => "debug",
+            Self::Release => "release",
+            Self::Native => "native",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Debug => Self::Release,
+            Self::Release => Self::Native,
+            Self::Native => Self::Debug,
+        }
+    }
+
+    pub fn rustc_flags(self) -> Vec<String> {
+        let flags: &[&str] = match self {
+            Self::Debug => &[],
+            Self::Release => &["-C", "opt-level=3"],
+            Self::Native => &["-C", "opt-level=3", "-C", "target-cpu=native"],
+        };
+        flags.iter().map(|f| f.to_string()).collect()
+    }
+}
+
+/// Resource limits applied to every solution binary. A value of 0 disables the limit.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(default)]
+pub struct RunLimits {
+    pub timeout_secs: u64,
+    pub memory_mb: u64,
+}
+
+impl Default for RunLimits {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 60,
+            memory_mb: 4096,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(default)]
+pub struct DayLimits {
+    pub timeout_secs: Option<u64>,
+    pub memory_mb: Option<u64>,
+}
+
+impl RunLimits {
+    pub fn with_override(self, day: Option<&DayLimits>) -> Self {
+        match day {
+            Some(day) => Self {
+                timeout_secs: day.timeout_secs.unwrap_or(self.timeout_secs),
+                memory_mb: day.memory_mb.unwrap_or(self.memory_mb),
+            },
+            None => self,
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use super::calendar;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How a year directory organizes its days.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// `{year}/NN/run.rs`, the layout `aoc new` scaffolds
+    Native,
+    /// `{year}/day_N/main.go` or `main.exs`, one program per day
+    DayDir,
+    /// `{year}/src/dayN/mod.rs`, modules of one crate behind a `Runner` trait
+    RunnerModule,
+    /// `{year}/src/day_N/main.rs`, one `[[bin]]` per day in a shared Cargo package
+    CargoBin,
+}
+
+impl Layout {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Native => "native",
+            Self::DayDir => "day dir",
+            Self::RunnerModule => "runner module",
+            Self::CargoBin => "cargo bin",
+        }
+    }
+}
+
+pub struct DayEntry {
+    pub day: u32,
+    pub layout: Layout,
+    pub path: PathBuf,
+    /// Solution source files found in `path`, e.g. `["main.exs", "main.go"]`
+    pub sources: Vec<String>,
+}
+
+pub struct YearEntry {
+    /// Directory name, e.g. "2025" or "2025--"
+    pub name: String,
+    /// The event the directory belongs to, taken from its leading digits
+    pub event: i32,
+    pub days: Vec<DayEntry>,
+}
+
+impl YearEntry {
+    pub fn day(&self, day: u32) -> Option<&DayEntry> {
+        self.days.iter().find(|entry| entry.day == day)
+    }
+
+    /// Number of puzzles in the year's event.
+    pub fn day_count(&self) -> u32 {
+        calendar::days_in_year(self.event)
+    }
+}
+
+/// Scans `root` for year directories (names starting with four digits) and
+/// the days they contain in any of the known layouts. Years are sorted
+/// newest first, days in order.
+pub fn discover(root: &Path) -> Vec<YearEntry> {
+    let mut years: Vec<YearEntry> = list_dirs(root)
+        .into_iter()
+        .filter_map(|(name, path)| {
+            let event = event_year(&name)?;
+            Some(YearEntry {
+                days: discover_days(&path),
+                name,
+                event,
+            })
+        })
+        .collect();
+    years.sort_by(|a, b| b.name.cmp(&a.name));
+    years
+}
+
+/// The directory of a day in whichever layout the year uses. Unknown days
+/// resolve to the native `{year}/NN` so they can be scaffolded there.
+pub fn resolve_day_dir(year: &str, day: &str) -> PathBuf {
+    let native = PathBuf::from(year).join(day);
+    if native.exists() {
+        return native;
+    }
+    let Ok(number) = day.parse::<u32>() else {
+        return native;
+    };
+    let year_dir = PathBuf::from(year);
+    [
+        year_dir.join(format!("day_{}", number)),
+        year_dir.join("src").join(format!("day_{}", number)),
+        year_dir.join("src").join(format!("day{}", number)),
+    ]
+    .into_iter()
+    .find(|dir| dir.is_dir())
+    .unwrap_or(native)
+}
+
+/// The event year a directory name belongs to: "2025--" -> 2025.
+pub fn event_year(name: &str) -> Option<i32> {
+    let digits = name.get(..4)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn discover_days(year_dir: &Path) -> Vec<DayEntry> {
+    let mut days = Vec::new();
+
+    for (name, path) in list_dirs(year_dir) {
+        if name.len() == 2
+            && let Ok(day) = name.parse()
+        {
+            days.push(entry(day, Layout::Native, path, &["run.rs"]));
+        } else if let Some(day) = name.strip_prefix("day_").and_then(|n| n.parse().ok()) {
+            let entry = entry(day, Layout::DayDir, path, &["main.go", "main.exs"]);
+            if !entry.sources.is_empty() {
+                days.push(entry);
+            }
+        }
+    }
+
+    for (name, path) in list_dirs(&year_dir.join("src")) {
+        if let Some(day) = name.strip_prefix("day_").and_then(|n| n.parse().ok()) {
+            days.push(entry(day, Layout::CargoBin, path, &["main.rs"]));
+        } else if let Some(day) = name.strip_prefix("day").and_then(|n| n.parse().ok()) {
+            days.push(entry(day, Layout::RunnerModule, path, &["mod.rs"]));
+        }
+    }
+
+    days.retain(|entry| entry.day >= 1);
+    days.sort_by_key(|entry| entry.day);
+    days
+}
+
+fn entry(day: u32, layout: Layout, path: PathBuf, candidates: &[&str]) -> DayEntry {
+    let sources = candidates
+        .iter()
+        .filter(|file| path.join(file).is_file())
+        .map(|file| file.to_string())
+        .collect();
+    DayEntry {
+        day,
+        layout,
+        path,
+        sources,
+    }
+}
+
+fn list_dirs(dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<_> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        // Keep paths relative to the repo root: "2025/01", not "./2025/01"
+        .map(|path| {
+            path.strip_prefix(".")
+                .map(Path::to_path_buf)
+                .unwrap_or(path)
+        })
+        .filter_map(|path| {
+            let name = path.file_name()?.to_string_lossy().to_string();
+            Some((name, path))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory holding `files`, each created empty with its parents.
+    fn tree(test: &str, files: &[&str]) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc_discovery_{}_{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        root
+    }
+
+    fn days(year: &YearEntry) -> Vec<(u32, Layout)> {
+        year.days
+            .iter()
+            .map(|entry| (entry.day, entry.layout))
+            .collect()
+    }
+
+    #[test]
+    fn reads_the_event_year_from_the_leading_digits() {
+        assert_eq!(event_year("2025"), Some(2025));
+        assert_eq!(event_year("2025--"), Some(2025));
+        assert_eq!(event_year("202"), None);
+        assert_eq!(event_year("20x5"), None);
+        assert_eq!(event_year("src"), None);
+    }
+
+    #[test]
+    fn finds_each_layout() {
+        let root = tree(
+            "layouts",
+            &[
+                "2025/01/run.rs",
+                "2025/02/run.rs",
+                "2025--/Cargo.toml",
+                "2025--/src/day_1/main.rs",
+                "2025--/src/day_2/main.rs",
+                "2024/day_1/main.go",
+                "2024/day_1/main.exs",
+                "2024/day_3/main.exs",
+                "2023/src/main.rs",
+                "2023/src/day1/mod.rs",
+                "2023/src/day4/mod.rs",
+            ],
+        );
+        let years = discover(&root);
+        let names: Vec<&str> = years.iter().map(|year| year.name.as_str()).collect();
+        assert_eq!(names, ["2025--", "2025", "2024", "2023"]);
+
+        assert!(days(&years[0]) == [(1, Layout::CargoBin), (2, Layout::CargoBin)]);
+        assert!(days(&years[1]) == [(1, Layout::Native), (2, Layout::Native)]);
+        assert!(days(&years[2]) == [(1, Layout::DayDir), (3, Layout::DayDir)]);
+        assert!(days(&years[3]) == [(1, Layout::RunnerModule), (4, Layout::RunnerModule)]);
+
+        assert_eq!(years[2].days[0].sources, ["main.go", "main.exs"]);
+        assert_eq!(years[0].days[1].path, root.join("2025--/src/day_2"));
+        assert_eq!(years[0].event, 2025);
+    }
+
+    #[test]
+    fn events_from_2025_have_twelve_days() {
+        let root = tree("day_count", &["2025--/01/run.rs", "2024/01/run.rs"]);
+        let years = discover(&root);
+        assert_eq!(years[0].day_count(), 12);
+        assert_eq!(years[1].day_count(), 25);
+    }
+
+    #[test]
+    fn ignores_entries_that_are_not_days() {
+        let root = tree(
+            "ignored",
+            &[
+                "src/main.rs",
+                "target/debug/aoc",
+                "notes/01/run.rs",
+                "README.md",
+                "2024/00/run.rs",
+                "2024/001/run.rs",
+                "2024/notes/todo.txt",
+                "2024/day_2/input.txt",
+                "2024/day_x/main.go",
+                "2024/src/utils/mod.rs",
+                "2024/src/day_0/main.rs",
+                "2024/README.md",
+                "2024/07/run.rs",
+            ],
+        );
+        let years = discover(&root);
+        assert_eq!(years.len(), 1);
+        assert!(days(&years[0]) == [(7, Layout::Native)]);
+    }
+
+    #[test]
+    fn resolves_day_dirs_in_any_layout() {
+        let root = tree(
+            "resolve",
+            &[
+                "2025/01/run.rs",
+                "2025--/src/day_3/main.rs",
+                "2024/day_5/main.go",
+                "2023/src/day2/mod.rs",
+            ],
+        );
+        let resolve =
+            |year: &str, day: &str| resolve_day_dir(&root.join(year).to_string_lossy(), day);
+        assert_eq!(resolve("2025", "01"), root.join("2025/01"));
+        assert_eq!(resolve("2025--", "03"), root.join("2025--/src/day_3"));
+        assert_eq!(resolve("2024", "05"), root.join("2024/day_5"));
+        assert_eq!(resolve("2023", "02"), root.join("2023/src/day2"));
+        // Unknown days go to the native layout, where `aoc new` creates them
+        assert_eq!(resolve("2023", "09"), root.join("2023/09"));
+        assert_eq!(resolve("2024", "xx"), root.join("2024/xx"));
+    }
+}
@@ -0,0 +1,276 @@
+use super::protocol;
+use super::runner::PART_ENV;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::Path;
+
+/// Whether `module_dir` is a day module (`dayN/mod.rs`) of a crate whose
+/// `main.rs` defines the `Runner` trait, like the 2023 layout.
+pub fn is_runner_module(module_dir: &Path) -> bool {
+    module_dir.join("mod.rs").is_file()
+        && module_dir
+            .parent()
+            .and_then(|src| fs::read_to_string(src.join("main.rs")).ok())
+            .is_some_and(|main| main.contains("trait Runner"))
+}
+
+/// Generates a single-file program that runs one day module through the
+/// crate's `Runner` trait: it copies the trait from `main.rs`, includes the
+/// module by path, and prints the `PART1:`/`PART2:` protocol for stdin along
+/// with `timing` messages for the parse and each part. The module is used as
+/// it is; see `constructor` for how the day's struct is created.
+pub fn generate(module_dir: &Path) -> Result<String, String> {
+    let module_path = module_dir.join("mod.rs");
+    let main_path = module_dir.join("../main.rs");
+    let module = fs::read_to_string(&module_path)
+        .map_err(|e| format!("Failed to read {}: {}", module_path.display(), e))?;
+    let main = fs::read_to_string(&main_path)
+        .map_err(|e| format!("Failed to read {}: {}", main_path.display(), e))?;
+
+    let runner_trait = extract_item(&main, "trait Runner")
+        .ok_or_else(|| format!("No `trait Runner` in {}", main_path.display()))?;
+    let runner = module
+        .split("impl Runner for ")
+        .nth(1)
+        .map(|rest| {
+            rest.chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect::<String>()
+        })
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| format!("No `impl Runner for` in {}", module_path.display()))?;
+    let module_name = module_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let constructor = constructor(&module, &runner)
+        .map(|expr| format!("{}::{}", module_name, expr))
+        .ok_or_else(|| format!("No `struct {}` in {}", runner, module_path.display()))?;
+    let absolute = fs::canonicalize(&module_path)
+        .map_err(|e| format!("Failed to resolve {}: {}", module_path.display(), e))?;
+
+    // The module is included by path, so its content has to show up in the
+    // generated source for the binary cache to notice edits
+    let mut hasher = DefaultHasher::new();
+    module.hash(&mut hasher);
+    runner_trait.hash(&mut hasher);
+
+    Ok(format!(
+        r#"// Generated by aoc to run {module_name} through the `Runner` trait.
+// Sources: {hash:016x}
+#![allow(dead_code, unused)]
+
+#[path = {path:?}]
+mod {module_name};
+
+{runner_trait}
+
+fn main() {{
+    use std::io::Read;
+    use std::panic::{{AssertUnwindSafe, catch_unwind}};
+
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input).unwrap();
+    // `parse` borrows the input for the rest of the program
+    let input: &'static str = Box::leak(input.into_boxed_str());
+    let mut runner = {constructor};
+    let only_part = std::env::var({part_env:?}).ok();
+
+    // `parse` runs again before each part so both start from a fresh parse;
+    // the parses add up to one `parse` stage, reported ahead of the parts
+    let mut parse_time = std::time::Duration::ZERO;
+    let mut part_times = Vec::new();
+    let mut run = |part: u8, solve: &dyn Fn(&mut {module_name}::{runner}) -> String| {{
+        let start = std::time::Instant::now();
+        runner.parse(input);
+        parse_time += start.elapsed();
+        let start = std::time::Instant::now();
+        let answer = catch_unwind(AssertUnwindSafe(|| solve(&mut runner)));
+        part_times.push((part, start.elapsed()));
+        match answer {{
+            Ok(answer) => println!("PART{{}}:{{}}", part, answer),
+            Err(_) => println!("PART{{}}:--", part),
+        }}
+    }};
+    if only_part.as_deref() != Some("2") {{
+        run(1, &|runner| match runner.part1() {{
+            Ok(answer) => answer.to_string(),
+            Err(e) => {{
+                eprintln!("part 1: {{}}", e);
+                String::from("--")
+            }}
+        }});
+    }}
+    if only_part.as_deref() != Some("1") {{
+        run(2, &|runner| match runner.part2() {{
+            Ok(answer) => answer.to_string(),
+            Err(e) => {{
+                eprintln!("part 2: {{}}", e);
+                String::from("--")
+            }}
+        }});
+    }}
+    drop(run);
+    timing("parse", parse_time);
+    for (part, elapsed) in part_times {{
+        timing(&format!("part{{}}", part), elapsed);
+    }}
+}}
+
+fn timing(stage: &str, elapsed: std::time::Duration) {{
+    println!(
+        "{{{{\"aoc\":{version},\"type\":\"timing\",\"stage\":\"{{}}\",\"micros\":{{}}}}}}",
+        stage,
+        elapsed.as_micros()
+    );
+}}
+"#,
+        hash = hasher.finish(),
+        path = absolute.display().to_string(),
+        part_env = PART_ENV,
+        version = protocol::VERSION,
+    ))
+}
+
+/// An expression creating the `runner` struct from inside its module's
+/// parent: `Name::default()` when the module implements `Default`, otherwise a
+/// literal giving every field its `Default` value, which needs the fields to be
+/// visible to the parent.
+fn constructor(module: &str, runner: &str) -> Option<String> {
+    let header = format!("struct {}", runner);
+    let start = module.match_indices(&header).map(|(i, _)| i).find(|&i| {
+        !module[i + header.len()..].starts_with(|c: char| c.is_alphanumeric() || c == '_')
+    })?;
+    // Attributes between the previous item and the struct
+    let attributes = &module[module[..start].rfind(['}', ';']).map_or(0, |i| i + 1)..start];
+    let derives_default = attributes.contains("derive(") && attributes.contains("Default");
+    if derives_default || module.contains(&format!("impl Default for {}", runner)) {
+        return Some(format!("{}::default()", runner));
+    }
+
+    let rest = &module[start + header.len()..];
+    let body_start = rest.find(['{', '(', ';'])?;
+    let body = match rest[body_start..].chars().next()? {
+        ';' => return Some(runner.to_string()),
+        '{' => extract_item(&rest[body_start..], "{")?,
+        _ => extract_parens(&rest[body_start..])?,
+    };
+    let fields = split_fields(&body[1..body.len() - 1]);
+    if body.starts_with('(') {
+        let values = vec!["Default::default()"; fields.len()];
+        return Some(format!("{}({})", runner, values.join(", ")));
+    }
+    let values: Vec<String> = fields
+        .iter()
+        .filter_map(|field| field.split_once(':'))
+        .filter_map(|(name, _)| name.split_whitespace().last())
+        .map(|name| format!("{}: Default::default()", name))
+        .collect();
+    Some(format!("{} {{ {} }}", runner, values.join(", ")))
+}
+
+/// The fields of a struct body, without their attributes and doc comments.
+fn split_fields(body: &str) -> Vec<String> {
+    let body: String = body
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("//") && !line.starts_with("#["))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut fields = Vec::new();
+    let mut depth = 0;
+    let mut field = String::new();
+    for c in body.chars() {
+        match c {
+            '<' | '(' | '[' | '{' => depth += 1,
+            '>' | ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                fields.push(std::mem::take(&mut field));
+                continue;
+            }
+            _ => {}
+        }
+        field.push(c);
+    }
+    fields.push(field);
+    fields.retain(|field| !field.trim().is_empty());
+    fields
+}
+
+/// The parenthesized group `source` starts with.
+fn extract_parens(source: &str) -> Option<&str> {
+    let mut depth = 0;
+    for (offset, c) in source.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&source[..offset + 1]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Cuts the item starting at `header` out of `source`, up to its matching brace.
+fn extract_item<'a>(source: &'a str, header: &str) -> Option<&'a str> {
+    let start = source.find(header)?;
+    let mut depth = 0;
+    for (offset, c) in source[start..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&source[start..start + offset + 1]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructs_with_default_when_implemented() {
+        let derived = "use crate::Runner;\n\n#[derive(Debug, Default)]\npub struct Day {\n    file: &'static str,\n}\n";
+        assert_eq!(
+            constructor(derived, "Day").as_deref(),
+            Some("Day::default()")
+        );
+        let manual = "pub struct Day { x: u8 }\nimpl Default for Day { fn default() -> Self { Day { x: 1 } } }";
+        assert_eq!(
+            constructor(manual, "Day").as_deref(),
+            Some("Day::default()")
+        );
+    }
+
+    #[test]
+    fn constructs_field_by_field_otherwise() {
+        let module = "#[derive(Default)]\nstruct Other;\n\npub struct Day {\n    /// The input\n    pub file: &'static str,\n    #[allow(unused)]\n    pub(crate) seen: HashMap<(i32, i32), Vec<u8>>,\n}\n";
+        assert_eq!(
+            constructor(module, "Day").as_deref(),
+            Some("Day { file: Default::default(), seen: Default::default() }")
+        );
+    }
+
+    #[test]
+    fn constructs_unit_and_tuple_structs() {
+        assert_eq!(
+            constructor("pub struct Day;", "Day").as_deref(),
+            Some("Day")
+        );
+        assert_eq!(
+            constructor("pub struct Day(pub Vec<(u8, u8)>, pub u8);", "Day").as_deref(),
+            Some("Day(Default::default(), Default::default())")
+        );
+        assert_eq!(constructor("pub struct DayTwo;", "Day"), None);
+    }
+}
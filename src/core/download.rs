@@ -0,0 +1,128 @@
+use super::calendar;
+use super::client::AocClient;
+use super::day_dir;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::path::Path;
+
+pub enum DownloadOutcome {
+    Downloaded {
+        bytes: usize,
+    },
+    /// `input.txt` already has content; the site is not asked again
+    AlreadyPresent,
+    Locked {
+        unlocks_at: DateTime<Utc>,
+    },
+}
+
+/// Downloads the day's puzzle input into `input.txt` unless it is already
+/// there or the puzzle has not unlocked yet.
+pub async fn download_input(
+    client: &impl AocClient,
+    year: &str,
+    day: &str,
+    now: DateTime<Utc>,
+) -> Result<DownloadOutcome> {
+    let path = day_dir(year, day).join("input.txt");
+    download_to(client, year, day, &path, now).await
+}
+
+async fn download_to(
+    client: &impl AocClient,
+    year: &str,
+    day: &str,
+    path: &Path,
+    now: DateTime<Utc>,
+) -> Result<DownloadOutcome> {
+    let has_input = fs::read_to_string(path)
+        .map(|s| !s.trim().is_empty())
+        .unwrap_or(false);
+    if has_input {
+        return Ok(DownloadOutcome::AlreadyPresent);
+    }
+
+    let unlocks_at = calendar::unlock_time(year, day)
+        .with_context(|| format!("Invalid puzzle date {}/{}", year, day))?;
+    if now < unlocks_at {
+        return Ok(DownloadOutcome::Locked { unlocks_at });
+    }
+
+    let input = client.fetch_input(year, day).await?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, &input).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(DownloadOutcome::Downloaded { bytes: input.len() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::client::HttpClient;
+    use super::super::client::stub::Stub;
+    use super::*;
+    use chrono::TimeZone;
+    use std::path::PathBuf;
+
+    fn input_path(test: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoc_download_{}_{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("input.txt")
+    }
+
+    fn after_unlock() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_user_agent_and_session_and_writes_the_input() {
+        let stub = Stub::start(200, "1\n2\n3\n").await;
+        let client = HttpClient::new(&stub.base_url, "secret").unwrap();
+        let path = input_path("ok");
+
+        let outcome = download_to(&client, "2020", "05", &path, after_unlock())
+            .await
+            .unwrap();
+
+        assert!(matches!(outcome, DownloadOutcome::Downloaded { bytes: 6 }));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2020/day/5/input "));
+        assert!(requests[0].contains("user-agent: aoc-cli/"));
+        assert!(requests[0].contains("cookie: session=secret"));
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[tokio::test]
+    async fn refuses_to_fetch_before_unlock() {
+        let stub = Stub::start(200, "early").await;
+        let client = HttpClient::new(&stub.base_url, "secret").unwrap();
+        let path = input_path("locked");
+        let before = Utc.with_ymd_and_hms(2020, 12, 5, 4, 59, 59).unwrap();
+
+        let outcome = download_to(&client, "2020", "05", &path, before)
+            .await
+            .unwrap();
+
+        assert!(matches!(outcome, DownloadOutcome::Locked { .. }));
+        assert!(stub.requests().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn error_responses_leave_no_input_file() {
+        for status in [400, 404] {
+            let stub = Stub::start(status, "Please don't repeatedly request this endpoint").await;
+            let client = HttpClient::new(&stub.base_url, "secret").unwrap();
+            let path = input_path(&format!("error{}", status));
+
+            let result = download_to(&client, "2020", "05", &path, after_unlock()).await;
+
+            assert!(result.is_err());
+            assert!(!path.exists());
+        }
+    }
+}
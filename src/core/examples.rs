@@ -0,0 +1,175 @@
+use super::day_dir;
+use super::inputs::read_non_empty;
+use super::puzzle::{self, Article, BlockKind};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Test case directory for a part 2 example that differs from part 1's.
+const PART2_CASE: &str = "part2";
+
+/// A `<pre><code>` block of the description, numbered in page order.
+pub struct CodeBlock {
+    pub part: u8,
+    pub text: String,
+}
+
+/// Every code block of the description, part 1's article first.
+pub fn code_blocks(articles: &[Article]) -> Vec<CodeBlock> {
+    articles
+        .iter()
+        .zip(1..)
+        .flat_map(|(article, part)| {
+            article
+                .blocks
+                .iter()
+                .filter(|block| block.kind == BlockKind::Code)
+                .map(move |block| CodeBlock {
+                    part,
+                    text: block.text(),
+                })
+        })
+        .collect()
+}
+
+/// The first code block after a paragraph mentioning "example".
+pub fn suggest_example(article: &Article) -> Option<String> {
+    let start = article.blocks.iter().position(|block| {
+        block.kind != BlockKind::Code && block.text().to_lowercase().contains("example")
+    })?;
+    article.blocks[start..]
+        .iter()
+        .find(|block| block.kind == BlockKind::Code)
+        .map(|block| block.text())
+}
+
+/// The last emphasized code value, which is where AoC states the example's answer.
+pub fn suggest_answer(article: &Article) -> Option<String> {
+    article
+        .blocks
+        .iter()
+        .filter(|block| block.kind != BlockKind::Code)
+        .flat_map(|block| &block.spans)
+        .filter(|span| span.code && span.emphasis)
+        .map(|span| span.text.trim().to_string())
+        .rfind(|text| !text.is_empty())
+}
+
+/// Fills empty example files from the cached description: `test.txt` and
+/// `solution_1.txt` from part 1, and `solution_2.txt` from part 2 once it is
+/// unlocked. A part 2 example that differs from part 1's goes to
+/// `tests/part2/` instead. Existing content is never overwritten; returns the
+/// files that were written.
+pub fn fill_from_puzzle(year: &str, day: &str) -> Result<Vec<String>> {
+    let Some(html) = puzzle::load_cached(year, day) else {
+        return Ok(Vec::new());
+    };
+    let articles = puzzle::parse(&html);
+    let base = day_dir(year, day);
+    let mut written = Vec::new();
+
+    if let Some(part1) = articles.first() {
+        if let Some(example) = suggest_example(part1) {
+            fill(&base, "test.txt", &example, &mut written)?;
+        }
+        if let Some(answer) = suggest_answer(part1) {
+            fill(&base, "solution_1.txt", &answer, &mut written)?;
+        }
+    }
+
+    if let Some(part2) = articles.get(1) {
+        let example = suggest_example(part2);
+        let test = read_non_empty(&base.join("test.txt"));
+        let dir = match example {
+            Some(example) if test.as_deref().map(str::trim) != Some(example.trim()) => {
+                let dir = base.join("tests").join(PART2_CASE);
+                fill(&dir, "input.txt", &example, &mut written)?;
+                dir
+            }
+            _ => base.clone(),
+        };
+        if let Some(answer) = suggest_answer(part2) {
+            fill(&dir, "solution_2.txt", &answer, &mut written)?;
+        }
+    }
+
+    Ok(written)
+}
+
+/// Writes a code block picked by hand as the example for its part,
+/// replacing whatever the heuristic chose. Returns the file written.
+pub fn use_block(year: &str, day: &str, block: &CodeBlock) -> Result<PathBuf> {
+    let base = day_dir(year, day);
+    let path = match block.part {
+        1 => base.join("test.txt"),
+        _ => base.join("tests").join(PART2_CASE).join("input.txt"),
+    };
+    write(&path, &block.text)?;
+    Ok(path)
+}
+
+fn fill(dir: &Path, file: &str, content: &str, written: &mut Vec<String>) -> Result<()> {
+    let path = dir.join(file);
+    if read_non_empty(&path).is_some() {
+        return Ok(());
+    }
+    write(&path, content)?;
+    written.push(path.display().to_string());
+    Ok(())
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, format!("{}\n", content.trim_end()))
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART1: &str = r#"<article><h2>--- Day 1: Historian Hysteria ---</h2>
+<pre><code>not the example</code></pre>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Another block:</p>
+<pre><code>later</code></pre>
+<p>In the example above, this is <code>2 + 1 = <em>3</em></code>, so the total is <code><em>11</em></code>.</p>
+</article>"#;
+
+    fn article(html: &str) -> Article {
+        puzzle::parse(html).remove(0)
+    }
+
+    #[test]
+    fn suggests_the_first_code_block_after_an_example_paragraph() {
+        assert_eq!(
+            suggest_example(&article(PART1)).as_deref(),
+            Some("3   4\n4   3")
+        );
+    }
+
+    #[test]
+    fn suggests_no_example_without_a_mention() {
+        let html = "<article><p>Input:</p><pre><code>1</code></pre></article>";
+        assert_eq!(suggest_example(&article(html)), None);
+    }
+
+    #[test]
+    fn suggests_the_last_emphasized_code_as_the_answer() {
+        assert_eq!(suggest_answer(&article(PART1)).as_deref(), Some("11"));
+    }
+
+    #[test]
+    fn numbers_code_blocks_by_part() {
+        let html = format!("{}<article><pre><code>two</code></pre></article>", PART1);
+        let blocks = code_blocks(&puzzle::parse(&html));
+        let parts: Vec<u8> = blocks.iter().map(|block| block.part).collect();
+        assert_eq!(parts, [1, 1, 1, 2]);
+        assert_eq!(blocks[3].text, "two");
+    }
+}
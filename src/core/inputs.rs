@@ -0,0 +1,117 @@
+use super::answers::Answers;
+use super::day_dir;
+use std::fs;
+use std::path::Path;
+
+/// Example input names, the native `test.txt` first and then the older layouts'.
+const TEST_FILES: [&str; 2] = ["test.txt", "input_test.txt"];
+/// Real input names, likewise.
+const INPUT_FILES: [&str; 3] = ["input.txt", "input_1.txt", "input_p.txt"];
+/// Per-part examples of the 2023 layout; each case only checks its own part.
+const PART_TEST_FILES: [(&str, &str, usize); 2] =
+    [("t1", "input_t1.txt", 0), ("t2", "input_t2.txt", 1)];
+
+/// One stdin payload for a solution together with the answers it should produce.
+pub struct InputCase {
+    pub name: String,
+    pub is_test: bool,
+    pub content: String,
+    pub expected: [Option<String>; 2],
+    /// Set for examples that belong to a single part, like the 2023 layout's
+    /// `input_t1.txt`; the other part is neither run nor reported
+    pub only_part: Option<u8>,
+}
+
+/// Collects a day's inputs in run order: the legacy `test.txt` case, every
+/// `tests/<case>/` directory sorted by name, then the real `input.txt`, which
+/// is checked against the confirmed answers in `answers.json`.
+///
+/// A case directory holds `input.txt` and optional `solution_1.txt` /
+/// `solution_2.txt`, mirroring the files at the root of the day.
+pub fn load_cases(year: &str, day: &str) -> Vec<InputCase> {
+    let base = day_dir(year, day);
+    let mut cases = Vec::new();
+
+    let test = TEST_FILES
+        .iter()
+        .find_map(|file| read_non_empty(&base.join(file)));
+    if let Some(content) = test {
+        cases.push(InputCase {
+            name: String::from("test"),
+            is_test: true,
+            content,
+            expected: read_expected(&base),
+            only_part: None,
+        });
+    }
+
+    for (name, file, part) in PART_TEST_FILES {
+        if let Some(content) = read_non_empty(&base.join(file)) {
+            let mut expected = [None, None];
+            expected[part] = read_expected(&base)[part].take();
+            cases.push(InputCase {
+                name: name.to_string(),
+                is_test: true,
+                content,
+                expected,
+                only_part: Some(part as u8 + 1),
+            });
+        }
+    }
+
+    let mut case_dirs: Vec<_> = fs::read_dir(base.join("tests"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect()
+        })
+        .unwrap_or_default();
+    case_dirs.sort();
+    for dir in case_dirs {
+        let Some(content) = read_non_empty(&dir.join("input.txt")) else {
+            continue;
+        };
+        cases.push(InputCase {
+            name: dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            is_test: true,
+            content,
+            expected: read_expected(&dir),
+            only_part: None,
+        });
+    }
+
+    let input = INPUT_FILES
+        .iter()
+        .find_map(|file| read_non_empty(&base.join(file)));
+    if let Some(content) = input {
+        // A corrupt answers.json leaves the input unchecked; the runner warns about it
+        let answers = Answers::load(year, day).unwrap_or_default();
+        cases.push(InputCase {
+            name: String::from("input"),
+            is_test: false,
+            content,
+            expected: [answers.part1, answers.part2],
+            only_part: None,
+        });
+    }
+
+    cases
+}
+
+fn read_expected(dir: &Path) -> [Option<String>; 2] {
+    [
+        read_non_empty(&dir.join("solution_1.txt")).map(|s| s.trim().to_string()),
+        read_non_empty(&dir.join("solution_2.txt")).map(|s| s.trim().to_string()),
+    ]
+}
+
+pub(super) fn read_non_empty(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+}
@@ -0,0 +1,65 @@
+//! UI-independent logic shared by the TUI dashboard and the headless CLI.
+
+pub mod answers;
+pub mod backend;
+pub mod bench;
+pub mod cache;
+pub mod calendar;
+pub mod client;
+pub mod config;
+pub mod discovery;
+pub mod dispatch;
+pub mod download;
+pub mod examples;
+pub mod inputs;
+pub mod protocol;
+pub mod puzzle;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod status;
+pub mod submit;
+pub mod year;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Directory holding a day's solution and inputs, e.g. `2025/03`, or the
+/// day's directory in one of the older layouts (`2024/day_3`) when only that exists.
+pub fn day_dir(year: &str, day: &str) -> PathBuf {
+    discovery::resolve_day_dir(year, day)
+}
+
+/// Normalizes user-provided day numbers ("3", "03") to the directory name format.
+pub fn normalize_day(day: &str) -> String {
+    match day.trim().parse::<u32>() {
+        Ok(n) => format!("{:02}", n),
+        Err(_) => day.trim().to_string(),
+    }
+}
+
+/// A bar of up to `width` cells for `part` out of `total`, e.g. for a stage of a run.
+pub fn duration_bar(part: Duration, total: Duration, width: usize) -> String {
+    if total.is_zero() {
+        return String::new();
+    }
+    let fraction = (part.as_secs_f64() / total.as_secs_f64()).min(1.0);
+    let cells = (fraction * width as f64).round() as usize;
+    // Anything measurable gets at least a sliver
+    let cells = if cells == 0 && !part.is_zero() {
+        1
+    } else {
+        cells
+    };
+    "█".repeat(cells)
+}
+
+pub fn format_duration(d: Duration) -> String {
+    if d.as_secs() >= 1 {
+        format!("{:.2}s", d.as_secs_f64())
+    } else if d.as_millis() >= 1 {
+        format!("{}ms", d.as_millis())
+    } else {
+        format!("{}µs", d.as_micros())
+    }
+}
@@ -0,0 +1,280 @@
+//! What a solution prints on stdout and how the runner reads it.
+//!
+//! Version 1 is one JSON object per line, tagged with the protocol version:
+//!
+//! - `{"aoc":1,"type":"answer","part":1,"value":"1129"}`
+//! - `{"aoc":1,"type":"timing","stage":"part1","micros":1520}`
+//! - `{"aoc":1,"type":"log","message":"grid is 140x140"}`
+//! - `{"aoc":1,"type":"progress","done":40,"total":100}`
+//! - `{"aoc":1,"type":"warning","message":"input has a trailing blank line"}`
+//!
+//! The original `PART1:<answer>` / `PART2:<answer>` lines are still accepted.
+//! Anything else is kept as plain output.
+
+use serde::Deserialize;
+use std::time::Duration;
+
+pub const VERSION: u32 = 1;
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    Answer {
+        part: u8,
+        value: serde_json::Value,
+    },
+    /// A stage the solution measured itself, e.g. "parse", "part1", "part2"
+    Timing {
+        stage: String,
+        micros: u64,
+    },
+    Log {
+        message: String,
+    },
+    Progress {
+        done: u64,
+        total: Option<u64>,
+    },
+    Warning {
+        message: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    aoc: u32,
+    #[serde(flatten)]
+    message: Message,
+}
+
+pub enum OutputLine {
+    Message(Message),
+    /// A JSON line from a newer protocol than this runner understands
+    Unsupported(u32),
+    Plain(String),
+}
+
+pub fn parse_line(line: &str) -> OutputLine {
+    if let Some(answer) = line.strip_prefix("PART1:") {
+        return OutputLine::Message(Message::Answer {
+            part: 1,
+            value: serde_json::Value::String(answer.to_string()),
+        });
+    }
+    if let Some(answer) = line.strip_prefix("PART2:") {
+        return OutputLine::Message(Message::Answer {
+            part: 2,
+            value: serde_json::Value::String(answer.to_string()),
+        });
+    }
+    if line.trim_start().starts_with('{') {
+        if let Ok(envelope) = serde_json::from_str::<Envelope>(line) {
+            return if envelope.aoc > VERSION {
+                OutputLine::Unsupported(envelope.aoc)
+            } else {
+                OutputLine::Message(envelope.message)
+            };
+        }
+        // A future version may add message types this runner cannot parse
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(line)
+            && let Some(version) = value.get("aoc").and_then(|v| v.as_u64())
+            && version > VERSION as u64
+        {
+            return OutputLine::Unsupported(version as u32);
+        }
+    }
+    OutputLine::Plain(line.to_string())
+}
+
+/// Everything a solution reported for one input.
+#[derive(Default)]
+pub struct Transcript {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub timings: Vec<(String, Duration)>,
+    pub logs: Vec<String>,
+    pub warnings: Vec<String>,
+    /// Lines outside the protocol
+    pub plain: Vec<String>,
+}
+
+impl Transcript {
+    pub fn read(stdout: &str) -> Self {
+        let mut transcript = Self::default();
+        let mut unsupported = None;
+        for line in stdout.lines() {
+            match parse_line(line) {
+                OutputLine::Message(Message::Answer { part, value }) => {
+                    let answer = match value {
+                        serde_json::Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    match part {
+                        1 => transcript.part1 = Some(answer),
+                        2 => transcript.part2 = Some(answer),
+                        _ => transcript
+                            .warnings
+                            .push(format!("answer for unknown part {}", part)),
+                    }
+                }
+                OutputLine::Message(Message::Timing { stage, micros }) => {
+                    transcript
+                        .timings
+                        .push((stage, Duration::from_micros(micros)));
+                }
+                OutputLine::Message(Message::Log { message }) => transcript.logs.push(message),
+                OutputLine::Message(Message::Warning { message }) => {
+                    transcript.warnings.push(message)
+                }
+                // Only useful while the solution runs
+                OutputLine::Message(Message::Progress { .. }) => {}
+                OutputLine::Unsupported(version) => unsupported = Some(version),
+                OutputLine::Plain(line) => transcript.plain.push(line),
+            }
+        }
+        if let Some(version) = unsupported {
+            transcript.warnings.push(format!(
+                "ignored output in protocol version {} (this runner speaks {})",
+                version, VERSION
+            ));
+        }
+        transcript
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_legacy_answer_lines() {
+        assert!(matches!(
+            parse_line("PART1:1129"),
+            OutputLine::Message(Message::Answer { part: 1, value }) if value == "1129"
+        ));
+        assert!(matches!(
+            parse_line("PART2:"),
+            OutputLine::Message(Message::Answer { part: 2, value }) if value == ""
+        ));
+    }
+
+    #[test]
+    fn parses_json_messages() {
+        assert!(matches!(
+            parse_line(r#"{"aoc":1,"type":"answer","part":2,"value":42}"#),
+            OutputLine::Message(Message::Answer { part: 2, value }) if value == 42
+        ));
+        assert!(matches!(
+            parse_line(r#"{"aoc":1,"type":"timing","stage":"parse","micros":1520}"#),
+            OutputLine::Message(Message::Timing { stage, micros: 1520 }) if stage == "parse"
+        ));
+        assert!(matches!(
+            parse_line(r#"{"aoc":1,"type":"progress","done":40}"#),
+            OutputLine::Message(Message::Progress {
+                done: 40,
+                total: None
+            })
+        ));
+        assert!(matches!(
+            parse_line(r#"  {"type":"log","aoc":1,"message":"hi"}"#),
+            OutputLine::Message(Message::Log { message }) if message == "hi"
+        ));
+    }
+
+    #[test]
+    fn flags_newer_versions() {
+        assert!(matches!(
+            parse_line(r#"{"aoc":2,"type":"answer","part":1,"value":"1"}"#),
+            OutputLine::Unsupported(2)
+        ));
+        assert!(matches!(
+            parse_line(r#"{"aoc":3,"type":"hologram"}"#),
+            OutputLine::Unsupported(3)
+        ));
+    }
+
+    #[test]
+    fn keeps_everything_else_as_plain_output() {
+        for line in [
+            "Counter 1: 1129",
+            r#"{"aoc":1,"type":"hologram"}"#,
+            r#"{"x":1}"#,
+            "{not json",
+        ] {
+            assert!(matches!(parse_line(line), OutputLine::Plain(plain) if plain == line));
+        }
+    }
+
+    #[test]
+    fn transcript_collects_each_kind() {
+        let stdout = concat!(
+            "hello\n",
+            "PART1:7\n",
+            r#"{"aoc":1,"type":"answer","part":2,"value":[1,2]}"#,
+            "\n",
+            r#"{"aoc":1,"type":"timing","stage":"part1","micros":5}"#,
+            "\n",
+            r#"{"aoc":1,"type":"answer","part":3,"value":"x"}"#,
+            "\n",
+            r#"{"aoc":9,"type":"answer","part":1,"value":"x"}"#,
+            "\n",
+        );
+        let transcript = Transcript::read(stdout);
+        assert_eq!(transcript.part1.as_deref(), Some("7"));
+        assert_eq!(transcript.part2.as_deref(), Some("[1,2]"));
+        assert_eq!(
+            transcript.timings,
+            [(String::from("part1"), Duration::from_micros(5))]
+        );
+        assert_eq!(transcript.plain, ["hello"]);
+        assert_eq!(transcript.warnings.len(), 2);
+        assert!(transcript.warnings[1].contains("version 9"));
+    }
+
+    /// Answers part 1 with characters that need escaping; part 2 is unwritten.
+    struct Escaped;
+
+    impl aoc_core::Solution for Escaped {
+        type Input = String;
+        type Answer1 = String;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<String, aoc_core::Error> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &String) -> Result<String, aoc_core::Error> {
+            Ok(format!("\"{}\" \\ \t\u{1b}é", input))
+        }
+
+        fn part2(_: &String) -> Result<u64, aoc_core::Error> {
+            Err(aoc_core::Error::NotImplemented)
+        }
+    }
+
+    #[test]
+    fn reads_what_aoc_core_writes() {
+        let mut stdout = Vec::new();
+        aoc_core::solve::<Escaped>("a\nb\n", &mut stdout);
+        let stdout = String::from_utf8(stdout).unwrap();
+        for line in stdout.lines() {
+            assert!(
+                matches!(parse_line(line), OutputLine::Message(_)),
+                "{}",
+                line
+            );
+        }
+
+        let transcript = Transcript::read(&stdout);
+        assert_eq!(transcript.part1.as_deref(), Some("\"a\nb\" \\ \t\u{1b}é"));
+        assert_eq!(transcript.part2.as_deref(), Some("--"));
+        let stages: Vec<&str> = transcript
+            .timings
+            .iter()
+            .map(|(stage, _)| stage.as_str())
+            .collect();
+        assert_eq!(stages, ["parse", "part1", "part2"]);
+        assert!(transcript.plain.is_empty());
+        assert!(transcript.warnings.is_empty());
+    }
+}
@@ -0,0 +1,313 @@
+use super::client::AocClient;
+use super::day_dir;
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+/// One `<article>` of the puzzle page; part 2 gets its own once unlocked.
+pub struct Article {
+    pub blocks: Vec<TextBlock>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BlockKind {
+    Heading,
+    Paragraph,
+    ListItem,
+    /// A `<pre><code>` block; its text keeps the original line breaks
+    Code,
+}
+
+pub struct TextBlock {
+    pub kind: BlockKind,
+    pub spans: Vec<TextSpan>,
+}
+
+pub struct TextSpan {
+    pub text: String,
+    pub code: bool,
+    pub emphasis: bool,
+}
+
+impl TextBlock {
+    pub fn text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+}
+
+/// Reads the cached description from `{year}/{day}/puzzle.html`.
+pub fn load_cached(year: &str, day: &str) -> Option<String> {
+    fs::read_to_string(path(year, day))
+        .ok()
+        .filter(|html| !html.trim().is_empty())
+}
+
+/// Downloads the puzzle page and caches its articles in `puzzle.html`.
+/// Called again after part 1 is solved to pick up part 2.
+pub async fn fetch_description(client: &impl AocClient, year: &str, day: &str) -> Result<String> {
+    let page = client.fetch_puzzle(year, day).await?;
+    let articles = extract_articles(&page);
+    if articles.is_empty() {
+        anyhow::bail!("No puzzle description found on the {}/{} page", year, day);
+    }
+    let path = path(year, day);
+    fs::create_dir_all(day_dir(year, day))?;
+    fs::write(&path, &articles).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(articles)
+}
+
+/// Keeps only the `<article>` elements of a page, dropping navigation and scripts.
+fn extract_articles(page: &str) -> String {
+    let mut articles = String::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<article") {
+        let Some(len) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + len + "</article>".len();
+        articles.push_str(&rest[start..end]);
+        articles.push('\n');
+        rest = &rest[end..];
+    }
+    articles
+}
+
+/// Parses the articles of a puzzle page into headings, paragraphs, list items
+/// and code blocks. Only the handful of tags AoC uses are understood; any other
+/// markup is dropped and its text kept.
+pub fn parse(html: &str) -> Vec<Article> {
+    let mut parser = Parser::default();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(tag_body) = rest.strip_prefix('<') {
+            let end = tag_body.find('>').unwrap_or(tag_body.len());
+            parser.tag(&tag_body[..end]);
+            rest = tag_body.get(end + 1..).unwrap_or_default();
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            parser.text(&decode_entities(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    parser.articles
+}
+
+#[derive(Default)]
+struct Parser {
+    articles: Vec<Article>,
+    in_article: bool,
+    in_pre: bool,
+    kind: Option<BlockKind>,
+    spans: Vec<TextSpan>,
+    code: usize,
+    emphasis: usize,
+}
+
+impl Parser {
+    fn tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), closing) {
+            ("article", false) => {
+                self.in_article = true;
+                self.articles.push(Article { blocks: Vec::new() });
+            }
+            ("article", true) => {
+                self.flush();
+                self.in_article = false;
+            }
+            ("h2", false) => self.start(BlockKind::Heading),
+            ("p", false) => self.start(BlockKind::Paragraph),
+            ("li", false) => self.start(BlockKind::ListItem),
+            ("pre", false) => {
+                self.start(BlockKind::Code);
+                self.in_pre = true;
+            }
+            ("pre", true) => {
+                self.flush();
+                self.in_pre = false;
+            }
+            ("h2" | "p" | "li", true) => self.flush(),
+            ("code", false) => self.code += 1,
+            ("code", true) => self.code = self.code.saturating_sub(1),
+            ("em", false) => self.emphasis += 1,
+            ("em", true) => self.emphasis = self.emphasis.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if !self.in_article || text.is_empty() {
+            return;
+        }
+        if self.kind.is_none() {
+            // Loose text between blocks, e.g. the "Your puzzle answer was" lines
+            if text.trim().is_empty() {
+                return;
+            }
+            self.kind = Some(BlockKind::Paragraph);
+        }
+
+        let text = if self.in_pre {
+            text.to_string()
+        } else {
+            collapse_whitespace(text)
+        };
+        self.spans.push(TextSpan {
+            text,
+            code: self.code > 0 && !self.in_pre,
+            emphasis: self.emphasis > 0,
+        });
+    }
+
+    fn start(&mut self, kind: BlockKind) {
+        self.flush();
+        self.kind = Some(kind);
+    }
+
+    fn flush(&mut self) {
+        let Some(kind) = self.kind.take() else {
+            return;
+        };
+        let spans = std::mem::take(&mut self.spans);
+        let Some(article) = self.articles.last_mut() else {
+            return;
+        };
+        let mut block = TextBlock { kind, spans };
+        if kind == BlockKind::Code {
+            // Code blocks end with a newline before `</code></pre>`
+            if let Some(last) = block.spans.last_mut() {
+                last.text.truncate(last.text.trim_end_matches('\n').len());
+            }
+        } else {
+            if let Some(first) = block.spans.first_mut() {
+                first.text = first.text.trim_start().to_string();
+            }
+            if let Some(last) = block.spans.last_mut() {
+                last.text = last.text.trim_end().to_string();
+            }
+        }
+        if !block.text().trim().is_empty() {
+            article.blocks.push(block);
+        }
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_space {
+                out.push(' ');
+            }
+            last_space = true;
+        } else {
+            out.push(c);
+            last_space = false;
+        }
+    }
+    out
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn path(year: &str, day: &str) -> PathBuf {
+    day_dir(year, day).join("puzzle.html")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><nav>Events</nav><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>Something is <em>wrong</em> with   global snow production.</p>
+<ul><li>Use <code>a &lt; b</code> here</li></ul>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>More.</p></article>
+<script>ignored()</script></main></body></html>"#;
+
+    #[test]
+    fn extracts_only_articles() {
+        let articles = extract_articles(PAGE);
+        assert!(articles.starts_with("<article"));
+        assert!(!articles.contains("Events"));
+        assert!(!articles.contains("54331"));
+        assert!(!articles.contains("ignored"));
+        assert_eq!(articles.matches("<article").count(), 2);
+    }
+
+    #[test]
+    fn parses_blocks_and_spans() {
+        let articles = parse(&extract_articles(PAGE));
+        assert_eq!(articles.len(), 2);
+
+        let blocks = &articles[0].blocks;
+        let kinds: Vec<BlockKind> = blocks.iter().map(|block| block.kind).collect();
+        assert!(
+            kinds
+                == [
+                    BlockKind::Heading,
+                    BlockKind::Paragraph,
+                    BlockKind::ListItem,
+                    BlockKind::Code
+                ]
+        );
+        assert_eq!(blocks[0].text(), "--- Day 1: Trebuchet?! ---");
+        assert_eq!(
+            blocks[1].text(),
+            "Something is wrong with global snow production."
+        );
+        assert!(
+            blocks[1]
+                .spans
+                .iter()
+                .any(|span| span.emphasis && span.text == "wrong")
+        );
+        assert!(
+            blocks[2]
+                .spans
+                .iter()
+                .any(|span| span.code && span.text == "a < b")
+        );
+        assert_eq!(blocks[3].text(), "1abc2\npqr3stu8vwx");
+        assert!(blocks[3].spans.iter().all(|span| !span.code));
+
+        assert_eq!(articles[1].blocks[0].text(), "--- Part Two ---");
+    }
+
+    #[test]
+    fn keeps_loose_article_text_as_a_paragraph() {
+        let articles = parse("<article>Loose <em>text</em></article>");
+        assert_eq!(articles[0].blocks.len(), 1);
+        assert!(articles[0].blocks[0].kind == BlockKind::Paragraph);
+        assert_eq!(articles[0].blocks[0].text(), "Loose text");
+    }
+
+    #[test]
+    fn decodes_entities_once() {
+        assert_eq!(decode_entities("&amp;lt; &quot;x&quot;"), "&lt; \"x\"");
+    }
+}
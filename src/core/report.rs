@@ -0,0 +1,256 @@
+use super::config::BuildProfile;
+use super::runner::{CompileOutcome, InputResult, RunReport, Verdict};
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordStatus {
+    Pass,
+    Fail,
+    Unknown,
+    Error,
+    CompileError,
+}
+
+/// Machine-readable result of running one input, emitted by `aoc run --format json`.
+#[derive(Serialize)]
+pub struct RunRecord {
+    pub year: String,
+    pub day: String,
+    pub input: Option<String>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub expected1: Option<String>,
+    pub expected2: Option<String>,
+    pub status: RecordStatus,
+    pub error: Option<String>,
+    /// Known-wrong-guess warnings for real-input answers, e.g. "part 2: already
+    /// rejected", followed by the warnings the solution raised itself
+    pub warnings: Vec<String>,
+    /// Debug logs the solution sent over the protocol
+    pub logs: Vec<String>,
+    /// Stdout outside the protocol
+    pub output: Option<String>,
+    pub profile: BuildProfile,
+    pub backend: &'static str,
+    /// Whether the binary came from the build cache, in which case there is no compile time
+    pub cached: bool,
+    pub compile_time_ms: Option<f64>,
+    pub run_time_ms: Option<f64>,
+    /// The solution's self-timed stages and the remaining overhead
+    pub stages: Vec<StageTime>,
+}
+
+#[derive(Serialize)]
+pub struct StageTime {
+    pub stage: String,
+    pub ms: f64,
+}
+
+impl RunRecord {
+    pub fn from_input(year: &str, day: &str, report: &RunReport, result: &InputResult) -> Self {
+        let compile = &report.compile;
+        let compile_time = match compile {
+            CompileOutcome::Compiled(compile_time) => Some(*compile_time),
+            _ => None,
+        };
+        let mut record = Self {
+            year: year.to_string(),
+            day: day.to_string(),
+            input: Some(result.name.clone()),
+            part1: None,
+            part2: None,
+            expected1: result.expected[0].clone(),
+            expected2: result.expected[1].clone(),
+            status: RecordStatus::Unknown,
+            error: None,
+            warnings: Vec::new(),
+            logs: Vec::new(),
+            output: None,
+            profile: report.profile,
+            backend: report.backend.name(),
+            cached: matches!(compile, CompileOutcome::Cached),
+            compile_time_ms: compile_time.map(millis),
+            run_time_ms: Some(millis(result.run_time)),
+            stages: result
+                .stage_breakdown()
+                .into_iter()
+                .map(|(stage, time)| StageTime {
+                    stage,
+                    ms: millis(time),
+                })
+                .collect(),
+        };
+
+        match &result.outcome {
+            Err(e) => {
+                record.status = RecordStatus::Error;
+                record.error = Some(e.to_string());
+            }
+            Ok(output) => {
+                let parts = [&output.part1, &output.part2];
+                record.part1 = output.part1.as_ref().map(|p| p.answer.clone());
+                record.part2 = output.part2.as_ref().map(|p| p.answer.clone());
+                record.status = combine((0..2).filter_map(|index| result.part_verdict(index)));
+                record.logs = output.logs.clone();
+                record.output = (!output.output.is_empty()).then(|| output.output.join("\n"));
+                for (part, result) in parts.iter().enumerate() {
+                    if let Some(warning) = result.as_ref().and_then(|r| r.warning.as_ref()) {
+                        record
+                            .warnings
+                            .push(format!("part {}: {}", part + 1, warning));
+                    }
+                }
+                record.warnings.extend(output.warnings.iter().cloned());
+            }
+        }
+        record
+    }
+
+    pub fn compile_failure(year: &str, day: &str, report: &RunReport) -> Self {
+        let error = match &report.compile {
+            CompileOutcome::Failed(stderr) | CompileOutcome::Unavailable(stderr) => stderr.clone(),
+            CompileOutcome::Compiled(_) | CompileOutcome::Cached | CompileOutcome::Interpreted => {
+                String::new()
+            }
+        };
+        Self {
+            year: year.to_string(),
+            day: day.to_string(),
+            input: None,
+            part1: None,
+            part2: None,
+            expected1: None,
+            expected2: None,
+            status: RecordStatus::CompileError,
+            error: Some(error),
+            warnings: Vec::new(),
+            logs: Vec::new(),
+            output: None,
+            profile: report.profile,
+            backend: report.backend.name(),
+            cached: false,
+            compile_time_ms: None,
+            run_time_ms: None,
+            stages: Vec::new(),
+        }
+    }
+
+    /// Whether this record should make a CI check fail.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.status,
+            RecordStatus::Fail | RecordStatus::Error | RecordStatus::CompileError
+        )
+    }
+}
+
+/// An input passes when every checked part passes and at least one part was checked.
+fn combine(verdicts: impl Iterator<Item = Verdict>) -> RecordStatus {
+    let mut status = RecordStatus::Unknown;
+    for verdict in verdicts {
+        match verdict {
+            Verdict::Fail => return RecordStatus::Fail,
+            Verdict::Pass => status = RecordStatus::Pass,
+            Verdict::Unknown => {}
+        }
+    }
+    status
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::backend::Backend;
+    use crate::core::runner::{ExecError, InputOutput, PartResult};
+
+    fn report() -> RunReport {
+        RunReport {
+            year: String::from("2024"),
+            day: String::from("01"),
+            profile: BuildProfile::Debug,
+            backend: Backend::Rustc,
+            compile: CompileOutcome::Cached,
+            inputs: Vec::new(),
+        }
+    }
+
+    fn output(part1: Option<&str>, part2: Option<&str>) -> InputOutput {
+        let part = |answer: Option<&str>, expected: &str| {
+            answer.map(|answer| PartResult {
+                answer: answer.to_string(),
+                expected: Some(expected.to_string()),
+                warning: None,
+            })
+        };
+        InputOutput {
+            part1: part(part1, "3"),
+            part2: part(part2, "4"),
+            timings: Vec::new(),
+            logs: Vec::new(),
+            warnings: Vec::new(),
+            output: Vec::new(),
+            stderr: String::new(),
+        }
+    }
+
+    fn input(outcome: Result<InputOutput, ExecError>) -> InputResult {
+        InputResult {
+            name: String::from("test"),
+            is_test: true,
+            expected: [Some(String::from("3")), Some(String::from("4"))],
+            run_time: Duration::from_millis(1),
+            outcome,
+        }
+    }
+
+    fn record(outcome: Result<InputOutput, ExecError>) -> RunRecord {
+        RunRecord::from_input("2024", "01", &report(), &input(outcome))
+    }
+
+    #[test]
+    fn passes_when_every_expected_answer_matches() {
+        let record = record(Ok(output(Some("3"), Some("4"))));
+        assert!(record.status == RecordStatus::Pass);
+        assert!(!record.is_failure());
+    }
+
+    #[test]
+    fn fails_a_wrong_answer() {
+        assert!(record(Ok(output(Some("3"), Some("5")))).status == RecordStatus::Fail);
+    }
+
+    #[test]
+    fn fails_an_expected_answer_that_was_not_printed() {
+        let record = record(Ok(output(Some("3"), None)));
+        assert!(record.status == RecordStatus::Fail);
+        assert_eq!(record.expected2.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn a_crash_is_an_error_that_keeps_the_expected_answers() {
+        let record = record(Err(ExecError::Failed(String::from(
+            "Runtime error: exit status: 3",
+        ))));
+        assert!(record.status == RecordStatus::Error);
+        assert!(record.is_failure());
+        assert_eq!(record.expected1.as_deref(), Some("3"));
+        assert_eq!(record.expected2.as_deref(), Some("4"));
+    }
+
+    #[test]
+    fn is_unknown_without_expected_answers() {
+        let mut input = input(Ok(output(Some("3"), None)));
+        input.expected = [None, None];
+        if let Ok(output) = &mut input.outcome {
+            output.part1.as_mut().unwrap().expected = None;
+        }
+        let record = RunRecord::from_input("2024", "01", &report(), &input);
+        assert!(record.status == RecordStatus::Unknown);
+    }
+}
@@ -0,0 +1,426 @@
+use super::answers::{Answers, GuessWarning};
+use super::backend::{self, Backend, Program};
+use super::config::{BuildProfile, RunLimits, RunOptions};
+use super::day_dir;
+use super::inputs;
+use super::protocol::{self, Message, OutputLine, Transcript};
+use super::status::LastRun;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::os::unix::process::ExitStatusExt;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+
+/// Set to "1" or "2" when a case only applies to that part.
+pub const PART_ENV: &str = "AOC_PART";
+
+pub enum ExecError {
+    Timeout(Duration),
+    OutOfMemory(u64),
+    Failed(String),
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Timeout(limit) => write!(f, "TIMEOUT after {}s", limit.as_secs()),
+            Self::OutOfMemory(limit_mb) => write!(f, "OOM above {}MB", limit_mb),
+            Self::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+pub enum CompileOutcome {
+    Compiled(Duration),
+    /// An identical source was already built; the cached binary is reused
+    Cached,
+    /// The compiler ran and rejected the source; holds its stderr
+    Failed(String),
+    /// The compiler could not be started at all
+    Unavailable(String),
+    /// The backend runs the source directly; there is nothing to build
+    Interpreted,
+}
+
+impl CompileOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Compiled(_) | Self::Cached | Self::Interpreted)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+pub struct PartResult {
+    pub answer: String,
+    pub expected: Option<String>,
+    /// Set for real-input answers that match a known wrong guess
+    pub warning: Option<GuessWarning>,
+}
+
+impl PartResult {
+    pub fn verdict(&self) -> Verdict {
+        match &self.expected {
+            Some(exp) if *exp == self.answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+pub struct InputOutput {
+    pub part1: Option<PartResult>,
+    pub part2: Option<PartResult>,
+    /// Stages the solution timed itself, in the order it reported them
+    pub timings: Vec<(String, Duration)>,
+    pub logs: Vec<String>,
+    /// Warnings the solution raised, plus any about its protocol output
+    pub warnings: Vec<String>,
+    /// Stdout lines outside the protocol, such as the older layouts' prints
+    pub output: Vec<String>,
+    pub stderr: String,
+}
+
+pub struct InputResult {
+    /// "test" for `test.txt`, the case name for `tests/<case>/`, "input" for `input.txt`
+    pub name: String,
+    pub is_test: bool,
+    /// The case's known answers, whether or not the solution printed any
+    pub expected: [Option<String>; 2],
+    pub run_time: Duration,
+    pub outcome: Result<InputOutput, ExecError>,
+}
+
+impl InputResult {
+    /// Section title shown above the input's results, e.g. "TEST · larger".
+    pub fn title(&self) -> String {
+        match (self.is_test, self.name.as_str()) {
+            (true, "test") => String::from("TEST"),
+            (true, name) => format!("TEST · {}", name),
+            (false, _) => String::from("INPUT"),
+        }
+    }
+
+    /// Verdict of part `index` (0 or 1) of a completed run. A part with a
+    /// known answer that printed none fails; `None` when there is neither.
+    pub fn part_verdict(&self, index: usize) -> Option<Verdict> {
+        let output = self.outcome.as_ref().ok()?;
+        let part = if index == 0 {
+            &output.part1
+        } else {
+            &output.part2
+        };
+        match (part, &self.expected[index]) {
+            (Some(part), _) => Some(part.verdict()),
+            (None, Some(_)) => Some(Verdict::Fail),
+            (None, None) => None,
+        }
+    }
+
+    /// The stages the solution timed itself followed by "overhead", the rest
+    /// of the measured run time (process start, passing the input). Empty when
+    /// the solution reported no timings.
+    pub fn stage_breakdown(&self) -> Vec<(String, Duration)> {
+        let Ok(output) = &self.outcome else {
+            return Vec::new();
+        };
+        if output.timings.is_empty() {
+            return Vec::new();
+        }
+        let measured: Duration = output.timings.iter().map(|(_, time)| *time).sum();
+        let mut stages = output.timings.clone();
+        stages.push((
+            String::from("overhead"),
+            self.run_time.saturating_sub(measured),
+        ));
+        stages
+    }
+}
+
+impl RunReport {
+    /// The real input's result, if `input.txt` was run.
+    pub fn real_input(&self) -> Option<&InputResult> {
+        self.inputs.iter().find(|input| !input.is_test)
+    }
+}
+
+pub struct RunReport {
+    pub year: String,
+    pub day: String,
+    pub profile: BuildProfile,
+    pub backend: Backend,
+    pub compile: CompileOutcome,
+    pub inputs: Vec<InputResult>,
+}
+
+/// Progress reported while `run_day` works through its phases.
+pub enum RunEvent<'a> {
+    Phase(String),
+    /// Building finished; `RunReport::compile` holds the outcome
+    Compiled(&'a RunReport),
+    Input(&'a InputResult),
+}
+
+/// Builds the day's solution with the backend its files call for and runs it
+/// against every test case and the real input. The outcome is recorded as the
+/// day's last run.
+pub async fn run_day(
+    year: &str,
+    day: &str,
+    options: RunOptions,
+    on_event: &mut (dyn FnMut(RunEvent<'_>) + Send),
+) -> RunReport {
+    let base = day_dir(year, day);
+    let limits = options.limits;
+    let backend = Backend::select(options.build_mode, &base);
+
+    let mut report = RunReport {
+        year: year.to_string(),
+        day: day.to_string(),
+        profile: options.profile,
+        backend,
+        compile: CompileOutcome::Cached,
+        inputs: Vec::new(),
+    };
+
+    on_event(RunEvent::Phase(format!(
+        "Compiling with {}",
+        backend.name()
+    )));
+    let program = match backend::build(year, day, backend, options.profile).await {
+        Ok((outcome, program)) => {
+            report.compile = outcome;
+            program
+        }
+        Err(outcome) => {
+            report.compile = outcome;
+            on_event(RunEvent::Compiled(&report));
+            LastRun::record(&report);
+            return report;
+        }
+    };
+    on_event(RunEvent::Compiled(&report));
+
+    let (answers, answers_error) = match Answers::load(year, day) {
+        Ok(answers) => (answers, None),
+        Err(e) => (Answers::default(), Some(format!("{:#}", e))),
+    };
+    for case in inputs::load_cases(year, day) {
+        on_event(RunEvent::Phase(format!("Running {}", case.name)));
+        let run_start = Instant::now();
+        let mut on_progress = |done: u64, total: Option<u64>| {
+            let progress = match total {
+                Some(total) if total > 0 => {
+                    format!("{}/{} ({}%)", done, total, done * 100 / total)
+                }
+                _ => done.to_string(),
+            };
+            on_event(RunEvent::Phase(format!(
+                "Running {} · {}",
+                case.name, progress
+            )));
+        };
+        let run_result = execute(
+            &program,
+            &case.content,
+            case.only_part,
+            limits,
+            &mut on_progress,
+        )
+        .await;
+        let run_time = run_start.elapsed();
+
+        let [expected_1, expected_2] = case.expected.clone();
+        let part_result = |part: u8, answer: String, expected: Option<String>| {
+            let warning = if case.is_test || expected.as_ref() == Some(&answer) {
+                None
+            } else {
+                answers.check(part, &answer)
+            };
+            PartResult {
+                answer,
+                expected,
+                warning,
+            }
+        };
+        let outcome = run_result.map(|(stdout, stderr)| {
+            let mut transcript = Transcript::read(&stdout);
+            match case.only_part {
+                Some(1) => transcript.part2 = None,
+                Some(_) => transcript.part1 = None,
+                None => {}
+            }
+            InputOutput {
+                part1: transcript
+                    .part1
+                    .map(|answer| part_result(1, answer, expected_1)),
+                part2: transcript
+                    .part2
+                    .map(|answer| part_result(2, answer, expected_2)),
+                timings: transcript.timings,
+                logs: transcript.logs,
+                warnings: transcript
+                    .warnings
+                    .into_iter()
+                    .chain(answers_error.clone().filter(|_| !case.is_test))
+                    .collect(),
+                output: transcript.plain,
+                stderr,
+            }
+        });
+
+        report.inputs.push(InputResult {
+            name: case.name,
+            is_test: case.is_test,
+            expected: case.expected,
+            run_time,
+            outcome,
+        });
+        on_event(RunEvent::Input(report.inputs.last().unwrap()));
+    }
+
+    LastRun::record(&report);
+    report
+}
+
+pub(super) async fn execute(
+    program: &Program,
+    input: &str,
+    only_part: Option<u8>,
+    limits: RunLimits,
+    on_progress: &mut (dyn FnMut(u64, Option<u64>) + Send),
+) -> Result<(String, String), ExecError> {
+    let mut command = Command::new(&program.command);
+    if let Some(part) = only_part {
+        // Lets solutions skip the part the input is not meant for
+        command.env(PART_ENV, part.to_string());
+    }
+    command
+        .args(&program.args)
+        .current_dir(&program.dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    let memory_mb = if program.memory_limit {
+        limits.memory_mb
+    } else {
+        0
+    };
+    if memory_mb > 0 {
+        let bytes = memory_mb.saturating_mul(1024 * 1024) as libc::rlim_t;
+        // SAFETY: only calls the async-signal-safe `setrlimit` between fork and exec
+        unsafe {
+            command.pre_exec(move || {
+                let limit = libc::rlimit {
+                    rlim_cur: bytes,
+                    rlim_max: bytes,
+                };
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| ExecError::Failed(format!("Failed to start: {}", e)))?;
+
+    let mut stdin = child.stdin.take();
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    let write_input = async {
+        if let Some(stdin) = stdin.as_mut() {
+            // Solutions that read their input file themselves may exit without
+            // draining stdin
+            match stdin.write_all(input.as_bytes()).await {
+                Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => {
+                    return Err(ExecError::Failed(format!("Failed to write input: {}", e)));
+                }
+                _ => {}
+            }
+        }
+        // Closing stdin lets the solution see the end of its input
+        drop(stdin);
+        Ok(())
+    };
+    // Stdout is read line by line so progress messages arrive while the
+    // solution is still running
+    let read_stdout = async {
+        let mut collected = Vec::new();
+        let Some(stdout) = stdout else {
+            return collected;
+        };
+        let mut reader = BufReader::new(stdout);
+        let mut line = Vec::new();
+        while let Ok(read) = reader.read_until(b'\n', &mut line).await
+            && read > 0
+        {
+            if let OutputLine::Message(Message::Progress { done, total }) =
+                protocol::parse_line(String::from_utf8_lossy(&line).trim_end())
+            {
+                on_progress(done, total);
+            }
+            collected.append(&mut line);
+        }
+        collected
+    };
+    let read_stderr = async {
+        let mut collected = Vec::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_end(&mut collected).await;
+        }
+        collected
+    };
+    let run = async {
+        let (written, stdout, stderr) = tokio::join!(write_input, read_stdout, read_stderr);
+        written?;
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| ExecError::Failed(format!("Runtime error: {}", e)))?;
+        Ok((status, stdout, stderr))
+    };
+
+    // Dropping the timed-out future drops the child, which kills it
+    let (status, stdout, stderr) = if limits.timeout_secs > 0 {
+        let timeout = Duration::from_secs(limits.timeout_secs);
+        match tokio::time::timeout(timeout, run).await {
+            Ok(result) => result?,
+            Err(_) => return Err(ExecError::Timeout(timeout)),
+        }
+    } else {
+        run.await?
+    };
+
+    let stdout = String::from_utf8_lossy(&stdout).to_string();
+    let stderr = String::from_utf8_lossy(&stderr).to_string();
+
+    // Rust aborts on a failed allocation after printing this message; a
+    // panic, which exits with status 101 instead, can never be mistaken for it
+    let out_of_memory =
+        status.signal() == Some(libc::SIGABRT) && stderr.contains("memory allocation of");
+    if memory_mb > 0 && out_of_memory {
+        return Err(ExecError::OutOfMemory(memory_mb));
+    }
+    // A crash must not pass as a run that simply printed no answers
+    if !status.success() {
+        return Err(ExecError::Failed(match stderr.trim() {
+            "" => format!("Runtime error: {}", status),
+            stderr => format!("Runtime error: {}\n{}", status, stderr),
+        }));
+    }
+
+    Ok((stdout, stderr))
+}
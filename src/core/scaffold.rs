@@ -0,0 +1,84 @@
+use super::{calendar, day_dir, discovery};
+use anyhow::{Context, Result, bail};
+use std::fs::{self, File};
+
+/// The `aoc-core` library crate at the repo root that templates depend on.
+const AOC_CORE_DIR: &str = "aoc-core";
+
+/// Creates the day directory with empty inputs, a `Solution` template on top
+/// of `aoc-core` and a Cargo.toml. Returns `false` when the day already exists.
+pub fn generate_missing_structure(year: &str, day: &str) -> Result<bool> {
+    let base = day_dir(year, day);
+    if base.exists() {
+        return Ok(false);
+    }
+    if let (Some(event), Ok(number)) = (discovery::event_year(year), day.parse::<u32>())
+        && !(1..=calendar::days_in_year(event)).contains(&number)
+    {
+        bail!(
+            "{} has days 1 to {}, not {}",
+            year,
+            calendar::days_in_year(event),
+            day
+        );
+    }
+
+    fs::create_dir_all(&base).with_context(|| format!("Failed to create {}", base.display()))?;
+
+    let files = ["test.txt", "input.txt", "solution_1.txt", "solution_2.txt"];
+    for file in files {
+        let path = base.join(file);
+        File::create(&path).with_context(|| format!("Failed to create {}", path.display()))?;
+    }
+
+    let template = format!(
+        r#"// Advent of Code {year} - Day {day}
+use aoc_core::{{Error, Solution}};
+
+struct Day;
+
+impl Solution for Day {{
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<Self::Answer1, Error> {{
+        Err(Error::NotImplemented)
+    }}
+
+    fn part2(_input: &Self::Input) -> Result<Self::Answer2, Error> {{
+        Err(Error::NotImplemented)
+    }}
+}}
+
+aoc_core::main!(Day);
+"#
+    );
+
+    fs::write(base.join("run.rs"), template).context("Failed to write run.rs")?;
+
+    // The empty [workspace] keeps the day out of the repo root's workspace
+    let cargo_toml = format!(
+        r#"[package]
+name = "aoc-{year}-{day}"
+version = "0.1.0"
+edition = "2021"
+
+[[bin]]
+name = "run"
+path = "run.rs"
+
+[dependencies]
+aoc-core = {{ path = "../../{AOC_CORE_DIR}" }}
+
+[workspace]
+"#
+    );
+
+    fs::write(base.join("Cargo.toml"), cargo_toml).context("Failed to write Cargo.toml")?;
+    Ok(true)
+}
@@ -0,0 +1,101 @@
+use super::answers::Answers;
+use super::backend::Backend;
+use super::config::BuildMode;
+use super::day_dir;
+use super::inputs;
+use super::runner::{CompileOutcome, RunReport, Verdict};
+use super::year::DayRow;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// File-based readiness of a day, shown as the indicator dots in the sidebar
+/// and the cells of the calendar.
+#[derive(Default)]
+pub struct DayStatus {
+    pub has_solution: bool,
+    pub has_test: bool,
+    pub has_input: bool,
+    /// Confirmed real-input answers in answers.json, 0 to 2
+    pub stars: u8,
+    pub last_run: Option<LastRun>,
+}
+
+impl DayStatus {
+    pub fn read(year: &str, day: &str) -> Self {
+        let cases = inputs::load_cases(year, day);
+        let answers = Answers::load(year, day).unwrap_or_default();
+        Self {
+            has_solution: Backend::detect(BuildMode::Auto, &day_dir(year, day)).is_some(),
+            has_test: cases.iter().any(|case| case.is_test),
+            has_input: cases.iter().any(|case| !case.is_test),
+            stars: [&answers.part1, &answers.part2]
+                .iter()
+                .filter(|answer| answer.is_some())
+                .count() as u8,
+            last_run: LastRun::load(year, day),
+        }
+    }
+}
+
+/// What the latest run of a day found, stored in `{year}/{day}/last_run.json`
+/// so the calendar can show it without running anything.
+#[derive(Serialize, Deserialize)]
+pub struct LastRun {
+    /// Combined verdict of the test cases of both parts, `None` without tests.
+    /// A build or run error counts as a failure.
+    pub tests: Option<Verdict>,
+    /// Run time of the real input, if it ran to completion
+    pub run_time_ms: Option<f64>,
+    pub ran_at: DateTime<Utc>,
+}
+
+impl LastRun {
+    pub fn load(year: &str, day: &str) -> Option<Self> {
+        fs::read_to_string(path(year, day))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+    }
+
+    /// Records `report` as the day's latest run. Failing to write only costs
+    /// the calendar its entry, so errors are ignored. Nothing is recorded
+    /// when there is no solution or its toolchain is missing: that says
+    /// nothing about the solution and would litter directories that are not days.
+    pub fn record(report: &RunReport) {
+        if matches!(report.compile, CompileOutcome::Unavailable(_))
+            || Backend::detect(BuildMode::Auto, &day_dir(&report.year, &report.day)).is_none()
+        {
+            return;
+        }
+        let row = DayRow::from_report(report);
+        let parts = row.parts.iter().filter_map(|part| part.tests);
+        let tests = if row.error.is_some() {
+            Some(Verdict::Fail)
+        } else {
+            parts.reduce(|a, b| match (a, b) {
+                (Verdict::Fail, _) | (_, Verdict::Fail) => Verdict::Fail,
+                (Verdict::Pass, Verdict::Pass) => Verdict::Pass,
+                _ => Verdict::Unknown,
+            })
+        };
+        let last_run = Self {
+            tests,
+            run_time_ms: row.run_time.map(|time| time.as_secs_f64() * 1000.0),
+            ran_at: Utc::now(),
+        };
+        if let Ok(json) = serde_json::to_string_pretty(&last_run) {
+            let _ = fs::write(path(&report.year, &report.day), json + "\n");
+        }
+    }
+
+    pub fn run_time(&self) -> Option<Duration> {
+        self.run_time_ms
+            .map(|ms| Duration::from_secs_f64(ms / 1000.0))
+    }
+}
+
+fn path(year: &str, day: &str) -> PathBuf {
+    day_dir(year, day).join("last_run.json")
+}
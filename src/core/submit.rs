@@ -0,0 +1,212 @@
+use super::answers::{Answers, Hint};
+use super::client::AocClient;
+use anyhow::{Result, bail};
+use std::fmt;
+use std::time::Duration;
+
+/// How the site responded to a submitted answer.
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect(Option<Hint>),
+    /// The part was already completed, so the answer was not checked
+    AlreadySolved,
+    /// Submitted too soon after the previous attempt; holds the wait if the page stated it
+    RateLimited(Option<Duration>),
+    /// The page did not match any known response; holds its text
+    Unrecognized(String),
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Incorrect(None) => write!(f, "incorrect"),
+            Self::Incorrect(Some(Hint::TooHigh)) => write!(f, "incorrect, too high"),
+            Self::Incorrect(Some(Hint::TooLow)) => write!(f, "incorrect, too low"),
+            Self::AlreadySolved => write!(f, "already solved"),
+            Self::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Self::RateLimited(None) => write!(f, "rate limited"),
+            Self::Unrecognized(text) => write!(f, "unrecognized response: {}", text),
+        }
+    }
+}
+
+/// Submits `answer` for `part` and records the verdict in `answers.json`:
+/// correct answers become the expected answers, wrong ones rejected guesses.
+/// Answers that `Answers::check` already rules out are not sent.
+pub async fn submit_answer(
+    client: &impl AocClient,
+    year: &str,
+    day: &str,
+    part: u8,
+    answer: &str,
+) -> Result<SubmitOutcome> {
+    if !(1..=2).contains(&part) {
+        bail!("Part must be 1 or 2, got {}", part);
+    }
+    let answer = answer.trim();
+    if answer.is_empty() || answer == "--" {
+        bail!("No part {} answer to submit", part);
+    }
+    let mut answers = Answers::load(year, day)?;
+    if let Some(warning) = answers.check(part, answer) {
+        bail!("Not submitting {}: {}", answer, warning);
+    }
+
+    let page = client.submit_answer(year, day, part, answer).await?;
+    let outcome = parse_response(&page);
+    match &outcome {
+        SubmitOutcome::Correct => answers.set(part, answer.to_string())?,
+        SubmitOutcome::Incorrect(hint) => answers.reject(part, answer.to_string(), *hint)?,
+        _ => return Ok(outcome),
+    }
+    answers.save(year, day)?;
+    Ok(outcome)
+}
+
+/// Classifies the `<article>` text of an answer response page.
+pub fn parse_response(page: &str) -> SubmitOutcome {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        SubmitOutcome::Incorrect(hint)
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited(parse_wait(&text))
+    } else if text.contains("Did you already complete it") {
+        SubmitOutcome::AlreadySolved
+    } else {
+        SubmitOutcome::Unrecognized(text.chars().take(120).collect())
+    }
+}
+
+/// The page's `<article>` (or the whole page) with tags stripped and whitespace collapsed.
+fn article_text(page: &str) -> String {
+    let article = page
+        .find("<article")
+        .and_then(|start| {
+            let end = page[start..].find("</article>")?;
+            Some(&page[start..start + end])
+        })
+        .unwrap_or(page);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 5s left to wait" into a duration.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let before = &text[..text.find(" left to wait")?];
+    let spec = &before[before.rfind("You have ")? + "You have ".len()..];
+    let mut secs = 0;
+    for token in spec.split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        secs += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::client::HttpClient;
+    use super::super::client::stub::Stub;
+    use super::*;
+
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are one gold star \
+        closer to saving Christmas. <a href=\"/2020/day/5#part2\">[Continue to Part Two]</a></p>\
+        </article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high.  If you're stuck, make sure you're using the full input data. Please wait one \
+        minute before trying again. <a href=\"/2020/day/5\">[Return to Day 5]</a></p></article></main>";
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too \
+        low.  Please wait one minute before trying again.</p></article></main>";
+    const ALREADY_SOLVED: &str = "<main><article><p>You don't seem to be solving the right \
+        level.  Did you already complete it? <a href=\"/2020/day/5\">[Return to Day 5]</a></p>\
+        </article></main>";
+    const RATE_LIMITED: &str = "<main><article><p>You gave an answer too recently; you have to \
+        wait after submitting an answer before trying again.  You have 1m 5s left to wait. \
+        <a href=\"/2020/day/5\">[Return to Day 5]</a></p></article></main>";
+
+    #[test]
+    fn parses_each_verdict() {
+        assert!(matches!(parse_response(CORRECT), SubmitOutcome::Correct));
+        assert!(matches!(
+            parse_response(TOO_HIGH),
+            SubmitOutcome::Incorrect(Some(Hint::TooHigh))
+        ));
+        assert!(matches!(
+            parse_response(TOO_LOW),
+            SubmitOutcome::Incorrect(Some(Hint::TooLow))
+        ));
+        assert!(matches!(
+            parse_response(ALREADY_SOLVED),
+            SubmitOutcome::AlreadySolved
+        ));
+        assert!(matches!(
+            parse_response(RATE_LIMITED),
+            SubmitOutcome::RateLimited(Some(wait)) if wait == Duration::from_secs(65)
+        ));
+        assert!(matches!(
+            parse_response("<article><p>Something else</p></article>"),
+            SubmitOutcome::Unrecognized(text) if text == "Something else"
+        ));
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            parse_wait("You have 30s left to wait."),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_wait("You have 1h 2m 3s left to wait."),
+            Some(Duration::from_secs(3723))
+        );
+        assert_eq!(parse_wait("You have a while left to wait."), None);
+    }
+
+    #[tokio::test]
+    async fn client_posts_the_answer_form() {
+        let stub = Stub::start(200, TOO_LOW).await;
+        let client = HttpClient::new(&stub.base_url, "secret").unwrap();
+
+        let page = client.submit_answer("2020", "05", 2, "1234").await.unwrap();
+
+        assert!(matches!(
+            parse_response(&page),
+            SubmitOutcome::Incorrect(Some(Hint::TooLow))
+        ));
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /2020/day/5/answer "));
+        assert!(requests[0].contains("cookie: session=secret"));
+        assert!(requests[0].ends_with("level=2&answer=1234"));
+    }
+}
@@ -0,0 +1,381 @@
+use super::backend::Backend;
+use super::calendar;
+use super::config::{BuildMode, Config};
+use super::runner::{self, CompileOutcome, InputOutput, PartResult, RunReport, Verdict};
+use super::{discovery, format_duration};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::{JoinError, JoinSet};
+
+/// One part of a day in the year table.
+#[derive(Clone)]
+pub struct PartCell {
+    /// The answer for the real input, if the solution gave one
+    pub answer: Option<String>,
+    /// The answer checked against the confirmed one in answers.json
+    pub verdict: Verdict,
+    /// Combined verdict of the part's test cases; `None` when it has none
+    pub tests: Option<Verdict>,
+}
+
+impl PartCell {
+    /// ✓ all tests passed, ✗ one failed, ? some lack an expected answer, · no tests.
+    pub fn tests_icon(&self) -> &'static str {
+        match self.tests {
+            Some(Verdict::Pass) => "✓",
+            Some(Verdict::Fail) => "✗",
+            Some(Verdict::Unknown) => "?",
+            None => "·",
+        }
+    }
+
+    fn empty() -> Self {
+        Self {
+            answer: None,
+            verdict: Verdict::Unknown,
+            tests: None,
+        }
+    }
+
+    /// The answer, marked ★ when it matches the confirmed one and ✗ when it does not.
+    pub fn answer_text(&self) -> String {
+        let answer = self.answer.as_deref().unwrap_or("-");
+        match self.verdict {
+            Verdict::Pass => format!("{} ★", answer),
+            Verdict::Fail => format!("{} ✗", answer),
+            Verdict::Unknown => answer.to_string(),
+        }
+    }
+}
+
+/// Why a day has no answers in the year table.
+#[derive(Clone)]
+pub enum DayError {
+    Compile(String),
+    /// The real input failed to run, e.g. a timeout
+    Run(String),
+}
+
+#[derive(Clone)]
+pub struct DayRow {
+    pub day: String,
+    pub parts: [PartCell; 2],
+    /// Run time of the real input, if it ran to completion
+    pub run_time: Option<Duration>,
+    pub error: Option<DayError>,
+}
+
+impl DayRow {
+    pub fn from_report(report: &RunReport) -> Self {
+        let error = match &report.compile {
+            CompileOutcome::Failed(_) => Some(DayError::Compile(String::from("compile error"))),
+            CompileOutcome::Unavailable(e) => Some(DayError::Compile(e.clone())),
+            _ => None,
+        };
+        let real = report.real_input();
+        let error = error.or_else(|| match real.map(|input| &input.outcome) {
+            Some(Err(e)) => Some(DayError::Run(e.to_string())),
+            _ => None,
+        });
+        let part = |index: usize| {
+            let pick = |output| part_of(output, index);
+            let real_part = real
+                .and_then(|input| input.outcome.as_ref().ok())
+                .and_then(pick);
+            let test_verdicts: Vec<Verdict> = report
+                .inputs
+                .iter()
+                .filter(|input| input.is_test)
+                .filter_map(|input| match &input.outcome {
+                    Ok(_) => input.part_verdict(index),
+                    // A crashed test counts against both parts
+                    Err(_) => Some(Verdict::Fail),
+                })
+                .collect();
+            PartCell {
+                answer: real_part.map(|part| part.answer.clone()),
+                verdict: real
+                    .and_then(|input| input.part_verdict(index))
+                    .unwrap_or(Verdict::Unknown),
+                tests: combine(&test_verdicts),
+            }
+        };
+        Self {
+            day: report.day.clone(),
+            parts: [part(0), part(1)],
+            // A timed-out or crashed run says nothing about the solution's speed
+            run_time: real
+                .filter(|input| input.outcome.is_ok())
+                .map(|input| input.run_time),
+            error,
+        }
+    }
+}
+
+impl DayRow {
+    /// The row of a day whose run never produced a report.
+    fn aborted(day: String, error: &JoinError) -> Self {
+        let reason = if error.is_panic() {
+            "runner panicked"
+        } else {
+            "run cancelled"
+        };
+        Self {
+            day,
+            parts: [PartCell::empty(), PartCell::empty()],
+            run_time: None,
+            error: Some(DayError::Run(String::from(reason))),
+        }
+    }
+}
+
+fn part_of(output: &InputOutput, index: usize) -> Option<&PartResult> {
+    if index == 0 {
+        output.part1.as_ref()
+    } else {
+        output.part2.as_ref()
+    }
+}
+
+/// Fail if any test failed, pass if all passed, unknown otherwise.
+fn combine(verdicts: &[Verdict]) -> Option<Verdict> {
+    if verdicts.is_empty() {
+        None
+    } else if verdicts.contains(&Verdict::Fail) {
+        Some(Verdict::Fail)
+    } else if verdicts.iter().all(|verdict| *verdict == Verdict::Pass) {
+        Some(Verdict::Pass)
+    } else {
+        Some(Verdict::Unknown)
+    }
+}
+
+pub struct YearReport {
+    pub year: String,
+    /// Days in order, as far as they have finished
+    pub rows: Vec<DayRow>,
+    /// Wall-clock time of the whole batch, set when every day finished
+    pub wall_time: Option<Duration>,
+}
+
+impl YearReport {
+    pub fn new(year: &str) -> Self {
+        Self {
+            year: year.to_string(),
+            rows: Vec::new(),
+            wall_time: None,
+        }
+    }
+
+    /// Adds a finished day, keeping the rows in day order.
+    pub fn insert(&mut self, row: DayRow) {
+        let index = self.rows.partition_point(|other| other.day < row.day);
+        self.rows.insert(index, row);
+    }
+
+    /// Sum of the days' real-input run times, the number the year is judged by.
+    pub fn total_run_time(&self) -> Duration {
+        self.rows.iter().filter_map(|row| row.run_time).sum()
+    }
+}
+
+/// Column widths of the year table: day, each part, run time.
+pub const COLUMNS: [usize; 4] = [4, 24, 24, 9];
+
+/// The table's header row, e.g. for `aoc year`.
+pub fn header() -> String {
+    format!(
+        "{:<w0$}{:<w1$}{:<w2$}{:>w3$}",
+        "Day",
+        "Part 1",
+        "Part 2",
+        "Time",
+        w0 = COLUMNS[0],
+        w1 = COLUMNS[1],
+        w2 = COLUMNS[2],
+        w3 = COLUMNS[3]
+    )
+}
+
+impl DayRow {
+    /// The row as plain text: each part's tests icon and answer, then the run time.
+    pub fn text(&self) -> String {
+        let day = format!("{:<w$}", self.day, w = COLUMNS[0]);
+        if let Some(DayError::Compile(e) | DayError::Run(e)) = &self.error
+            && self.parts.iter().all(|part| part.answer.is_none())
+        {
+            return format!("{}✗ {}", day, e);
+        }
+        let parts: String = self
+            .parts
+            .iter()
+            .zip(&COLUMNS[1..3])
+            .map(|(part, width)| {
+                let cell = format!("{} {}", part.tests_icon(), part.answer_text());
+                format!("{:<w$}", cell, w = *width)
+            })
+            .collect();
+        let time = self.run_time.map(format_duration).unwrap_or_default();
+        format!("{}{}{:>w$}", day, parts, time, w = COLUMNS[3])
+    }
+
+    /// A failed test, a wrong answer or an error, what `aoc year` exits non-zero for.
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some()
+            || self
+                .parts
+                .iter()
+                .any(|part| part.verdict == Verdict::Fail || part.tests == Some(Verdict::Fail))
+    }
+}
+
+/// Days of `year` that are unlocked and have a solution to run.
+pub fn runnable_days(year: &str) -> Vec<String> {
+    let now = Utc::now();
+    discovery::discover(Path::new("."))
+        .into_iter()
+        .find(|entry| entry.name == year)
+        .map(|entry| {
+            entry
+                .days
+                .iter()
+                .filter(|day| Backend::detect(BuildMode::Auto, &day.path).is_some())
+                .map(|day| format!("{:02}", day.day))
+                .filter(|day| calendar::is_unlocked(year, day, now))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Number of days run at once when no `--jobs` is given.
+pub fn default_jobs() -> usize {
+    std::thread::available_parallelism().map_or(4, |n| n.get())
+}
+
+/// Builds and runs every runnable day of `year`, at most `jobs` at a time,
+/// with each day's options from `config`. Rows are handed to `on_day` as the
+/// days finish, in whatever order that is. Run times of concurrent days
+/// compete for the CPU, so they read higher than a day run on its own.
+pub async fn run_year(
+    year: &str,
+    config: &Config,
+    jobs: usize,
+    on_day: &mut (dyn FnMut(&DayRow) + Send),
+) -> YearReport {
+    let started = Instant::now();
+    let permits = Arc::new(Semaphore::new(jobs.max(1)));
+    let mut tasks = JoinSet::new();
+    // A task that panics or is cancelled leaves no report naming its day
+    let mut days = HashMap::new();
+    for day in runnable_days(year) {
+        let options = config.run_options(year, &day);
+        let task_year = year.to_string();
+        let task_day = day.clone();
+        let permits = permits.clone();
+        let handle = tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            runner::run_day(&task_year, &task_day, options, &mut |_| {}).await
+        });
+        days.insert(handle.id(), day);
+    }
+
+    let mut report = YearReport::new(year);
+    while let Some(result) = tasks.join_next_with_id().await {
+        let row = match result {
+            Ok((_, day_report)) => DayRow::from_report(&day_report),
+            Err(e) => DayRow::aborted(days.remove(&e.id()).unwrap_or_default(), &e),
+        };
+        on_day(&row);
+        report.insert(row);
+    }
+    report.wall_time = Some(started.elapsed());
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(verdict: Verdict, tests: Option<Verdict>) -> PartCell {
+        PartCell {
+            answer: Some(String::from("42")),
+            verdict,
+            tests,
+        }
+    }
+
+    fn row(day: &str, run_ms: Option<u64>) -> DayRow {
+        DayRow {
+            day: day.to_string(),
+            parts: [
+                part(Verdict::Pass, Some(Verdict::Pass)),
+                part(Verdict::Unknown, None),
+            ],
+            run_time: run_ms.map(Duration::from_millis),
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn a_day_whose_task_died_is_a_failed_row() {
+        let panicked = tokio::spawn(async { panic!("boom") }).await.unwrap_err();
+        let row = DayRow::aborted(String::from("05"), &panicked);
+        assert_eq!(row.day, "05");
+        assert!(row.is_failure());
+        assert!(matches!(&row.error, Some(DayError::Run(e)) if e == "runner panicked"));
+
+        let task = tokio::spawn(std::future::pending::<()>());
+        task.abort();
+        let cancelled = task.await.unwrap_err();
+        let row = DayRow::aborted(String::from("06"), &cancelled);
+        assert!(matches!(&row.error, Some(DayError::Run(e)) if e == "run cancelled"));
+    }
+
+    #[test]
+    fn insert_keeps_days_in_order() {
+        let mut report = YearReport::new("2024");
+        for day in ["03", "01", "12", "02"] {
+            report.insert(row(day, Some(1)));
+        }
+        let days: Vec<&str> = report.rows.iter().map(|row| row.day.as_str()).collect();
+        assert_eq!(days, ["01", "02", "03", "12"]);
+    }
+
+    #[test]
+    fn total_skips_days_without_a_run_time() {
+        let mut report = YearReport::new("2024");
+        report.insert(row("01", Some(5)));
+        report.insert(row("02", None));
+        report.insert(row("03", Some(7)));
+        assert_eq!(report.total_run_time(), Duration::from_millis(12));
+    }
+
+    #[test]
+    fn failures_are_errors_wrong_answers_or_failed_tests() {
+        assert!(!row("01", Some(1)).is_failure());
+
+        let mut wrong = row("01", Some(1));
+        wrong.parts[1].verdict = Verdict::Fail;
+        assert!(wrong.is_failure());
+
+        let mut failed_test = row("01", Some(1));
+        failed_test.parts[0].tests = Some(Verdict::Fail);
+        assert!(failed_test.is_failure());
+
+        let mut error = row("01", None);
+        error.error = Some(DayError::Run(String::from("TIMEOUT after 60s")));
+        assert!(error.is_failure());
+    }
+
+    #[test]
+    fn combines_test_verdicts() {
+        assert!(combine(&[]).is_none());
+        assert!(combine(&[Verdict::Pass, Verdict::Pass]) == Some(Verdict::Pass));
+        assert!(combine(&[Verdict::Pass, Verdict::Unknown]) == Some(Verdict::Unknown));
+        assert!(combine(&[Verdict::Unknown, Verdict::Fail]) == Some(Verdict::Fail));
+    }
+}
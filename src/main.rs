@@ -1,14 +1,25 @@
 use crate::app::App;
+use crate::cli::Cli;
+use clap::Parser;
 use color_eyre::eyre::Result;
+use std::process::ExitCode;
 
 mod app;
+mod cli;
+mod core;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
     // 1. Setup color-eyre
     color_eyre::install()?;
 
-    // 2. Setup TUI Panic Hook
+    // 2. Headless subcommands skip the TUI entirely
+    let cli = Cli::parse();
+    if let Some(command) = cli.command {
+        return cli::execute(command).await;
+    }
+
+    // 3. Setup TUI Panic Hook
     // Ensure we restore the terminal if the app panics
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -16,15 +27,15 @@ async fn main() -> Result<()> {
         original_hook(panic_info);
     }));
 
-    // 3. Initialize Terminal
+    // 4. Initialize Terminal
     let mut terminal = ratatui::init();
 
-    // 4. Run the app
+    // 5. Run the app
     let app_result = App::new().run(&mut terminal).await;
 
-    // 5. Restore Terminal (Normal Exit)
+    // 6. Restore Terminal (Normal Exit)
     ratatui::restore();
 
-    // 6. Print errors if any
-    app_result
+    // 7. Print errors if any
+    app_result.map(|_| ExitCode::SUCCESS)
 }